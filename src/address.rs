@@ -0,0 +1,286 @@
+use core::{fmt::Display, str::FromStr};
+
+use heapless::String;
+
+use crate::{ed25519, error::DecodeError, ClaimableBalance, Contract, LiquidityPool, Strkey};
+
+/// Stores an address that can appear in a Soroban `SCAddress`: an account,
+/// muxed account, contract, claimable balance or liquidity pool.
+///
+/// Addresses are displayed and parsed as `G`, `M`, `C`, `B` or `L` strkeys.
+/// Parsing any other kind of strkey fails with
+/// [`DecodeError::UnexpectedKind`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::SerializeDisplay, serde_with::DeserializeFromStr)
+)]
+pub enum Address {
+    Account(ed25519::PublicKey),
+    MuxedAccount(ed25519::MuxedAccount),
+    Contract(Contract),
+    ClaimableBalance(ClaimableBalance),
+    LiquidityPool(LiquidityPool),
+}
+
+/// Stores the address of a classic account, either an account `G` or a muxed
+/// account `M`.
+///
+/// Muxed accounts are virtual accounts that share the balances of their base
+/// account, so two addresses with the same base account are the same account
+/// on the ledger. Use [`AccountAddress::is_same_account`] to compare them that
+/// way. The derived [`PartialEq`] compares the mux ID too.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::SerializeDisplay, serde_with::DeserializeFromStr)
+)]
+pub enum AccountAddress {
+    Account(ed25519::PublicKey),
+    MuxedAccount(ed25519::MuxedAccount),
+}
+
+impl Address {
+    // MuxedAccount is the longest address type.
+    const MAX_ENCODED_LEN: usize = ed25519::MuxedAccount::ENCODED_LEN;
+    const _ASSERTS: () = {
+        assert!(Self::MAX_ENCODED_LEN >= ed25519::PublicKey::ENCODED_LEN);
+        assert!(Self::MAX_ENCODED_LEN >= Contract::ENCODED_LEN);
+        assert!(Self::MAX_ENCODED_LEN >= ClaimableBalance::ENCODED_LEN);
+        assert!(Self::MAX_ENCODED_LEN >= LiquidityPool::ENCODED_LEN);
+    };
+
+    pub fn to_string(&self) -> String<{ Self::MAX_ENCODED_LEN }> {
+        let mut s: String<{ Self::MAX_ENCODED_LEN }> = String::new();
+        match self {
+            Self::Account(x) => s.push_str(x.to_string().as_str()).unwrap(),
+            Self::MuxedAccount(x) => s.push_str(x.to_string().as_str()).unwrap(),
+            Self::Contract(x) => s.push_str(x.to_string().as_str()).unwrap(),
+            Self::ClaimableBalance(x) => s.push_str(x.to_string().as_str()).unwrap(),
+            Self::LiquidityPool(x) => s.push_str(x.to_string().as_str()).unwrap(),
+        }
+        s
+    }
+
+    pub fn from_string(s: &str) -> Result<Self, DecodeError> {
+        Self::from_slice(s.as_bytes())
+    }
+
+    /// Decodes a `G`, `M`, `C`, `B` or `L` strkey.
+    ///
+    /// ### Errors
+    ///
+    /// If the strkey is invalid, or with [`DecodeError::UnexpectedKind`] if it
+    /// is a valid strkey of another kind.
+    pub fn from_slice(s: &[u8]) -> Result<Self, DecodeError> {
+        Self::try_from(Strkey::from_slice(s)?)
+    }
+}
+
+impl Display for Address {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(&self.to_string())
+    }
+}
+
+impl FromStr for Address {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Address::from_string(s)
+    }
+}
+
+impl TryFrom<Strkey> for Address {
+    type Error = DecodeError;
+
+    fn try_from(strkey: Strkey) -> Result<Self, Self::Error> {
+        match strkey {
+            Strkey::PublicKeyEd25519(x) => Ok(Self::Account(x)),
+            Strkey::MuxedAccountEd25519(x) => Ok(Self::MuxedAccount(x)),
+            Strkey::Contract(x) => Ok(Self::Contract(x)),
+            Strkey::ClaimableBalance(x) => Ok(Self::ClaimableBalance(x)),
+            Strkey::LiquidityPool(x) => Ok(Self::LiquidityPool(x)),
+            Strkey::PrivateKeyEd25519(_)
+            | Strkey::PreAuthTx(_)
+            | Strkey::HashX(_)
+            | Strkey::SignedPayloadEd25519(_) => Err(DecodeError::UnexpectedKind),
+        }
+    }
+}
+
+impl From<Address> for Strkey {
+    fn from(address: Address) -> Self {
+        match address {
+            Address::Account(x) => Strkey::PublicKeyEd25519(x),
+            Address::MuxedAccount(x) => Strkey::MuxedAccountEd25519(x),
+            Address::Contract(x) => Strkey::Contract(x),
+            Address::ClaimableBalance(x) => Strkey::ClaimableBalance(x),
+            Address::LiquidityPool(x) => Strkey::LiquidityPool(x),
+        }
+    }
+}
+
+#[cfg(feature = "serde-decoded")]
+mod address_decoded_serde_impl {
+    use super::*;
+    use crate::decoded_json_format::Decoded;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    // Addresses use the same JSON objects as the equivalent strkeys.
+
+    impl Serialize for Decoded<&Address> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Decoded(&Strkey::from(*self.0)).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Decoded<Address> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let Decoded(strkey) = Decoded::<Strkey>::deserialize(deserializer)?;
+            Ok(Decoded(
+                Address::try_from(strkey).map_err(de::Error::custom)?,
+            ))
+        }
+    }
+}
+
+impl AccountAddress {
+    const MAX_ENCODED_LEN: usize = ed25519::MuxedAccount::ENCODED_LEN;
+
+    /// Returns the base account, which is the account itself, or the account
+    /// the muxed account is multiplexed from.
+    pub fn base_account(&self) -> ed25519::PublicKey {
+        match self {
+            Self::Account(x) => *x,
+            Self::MuxedAccount(x) => ed25519::PublicKey(x.ed25519),
+        }
+    }
+
+    /// Returns the ID of a muxed account, or `None` for an account.
+    pub fn mux_id(&self) -> Option<u64> {
+        match self {
+            Self::Account(_) => None,
+            Self::MuxedAccount(x) => Some(x.id),
+        }
+    }
+
+    /// Returns true if both addresses have the same base account, ignoring
+    /// mux IDs.
+    pub fn is_same_account(&self, other: &Self) -> bool {
+        self.base_account() == other.base_account()
+    }
+
+    /// Returns the address of the base account, as a `G` address.
+    pub fn normalize(&self) -> Self {
+        Self::Account(self.base_account())
+    }
+
+    pub fn to_string(&self) -> String<{ Self::MAX_ENCODED_LEN }> {
+        let mut s: String<{ Self::MAX_ENCODED_LEN }> = String::new();
+        match self {
+            Self::Account(x) => s.push_str(x.to_string().as_str()).unwrap(),
+            Self::MuxedAccount(x) => s.push_str(x.to_string().as_str()).unwrap(),
+        }
+        s
+    }
+
+    pub fn from_string(s: &str) -> Result<Self, DecodeError> {
+        Self::from_slice(s.as_bytes())
+    }
+
+    /// Decodes a `G` or `M` strkey.
+    ///
+    /// ### Errors
+    ///
+    /// If the strkey is invalid, or with [`DecodeError::UnexpectedKind`] if it
+    /// is a valid strkey of another kind.
+    pub fn from_slice(s: &[u8]) -> Result<Self, DecodeError> {
+        Self::try_from(Strkey::from_slice(s)?)
+    }
+}
+
+impl Display for AccountAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(&self.to_string())
+    }
+}
+
+impl FromStr for AccountAddress {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AccountAddress::from_string(s)
+    }
+}
+
+impl From<ed25519::PublicKey> for AccountAddress {
+    fn from(key: ed25519::PublicKey) -> Self {
+        Self::Account(key)
+    }
+}
+
+impl From<ed25519::MuxedAccount> for AccountAddress {
+    fn from(muxed: ed25519::MuxedAccount) -> Self {
+        Self::MuxedAccount(muxed)
+    }
+}
+
+impl TryFrom<Strkey> for AccountAddress {
+    type Error = DecodeError;
+
+    fn try_from(strkey: Strkey) -> Result<Self, Self::Error> {
+        Self::try_from(Address::try_from(strkey)?)
+    }
+}
+
+impl TryFrom<Address> for AccountAddress {
+    type Error = DecodeError;
+
+    fn try_from(address: Address) -> Result<Self, Self::Error> {
+        match address {
+            Address::Account(x) => Ok(Self::Account(x)),
+            Address::MuxedAccount(x) => Ok(Self::MuxedAccount(x)),
+            Address::Contract(_) | Address::ClaimableBalance(_) | Address::LiquidityPool(_) => {
+                Err(DecodeError::UnexpectedKind)
+            }
+        }
+    }
+}
+
+impl From<AccountAddress> for Address {
+    fn from(address: AccountAddress) -> Self {
+        match address {
+            AccountAddress::Account(x) => Address::Account(x),
+            AccountAddress::MuxedAccount(x) => Address::MuxedAccount(x),
+        }
+    }
+}
+
+impl From<AccountAddress> for Strkey {
+    fn from(address: AccountAddress) -> Self {
+        Address::from(address).into()
+    }
+}
+
+#[cfg(feature = "serde-decoded")]
+mod account_address_decoded_serde_impl {
+    use super::*;
+    use crate::decoded_json_format::Decoded;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Serialize for Decoded<&AccountAddress> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Decoded(&Strkey::from(*self.0)).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Decoded<AccountAddress> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let Decoded(strkey) = Decoded::<Strkey>::deserialize(deserializer)?;
+            Ok(Decoded(
+                AccountAddress::try_from(strkey).map_err(de::Error::custom)?,
+            ))
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use core::{fmt::Display, str::FromStr};
+
+#[cfg(feature = "sha2")]
+use heapless::Vec;
+
+use crate::{ed25519, error::DecodeError};
+
+/// Stores a Stellar asset, either the native asset or an issued asset
+/// identified by its code and issuer.
+///
+/// The variant order, and the field order within each variant, match the
+/// canonical asset ordering used by stellar-core, so the derived [`Ord`] can be
+/// used wherever the protocol requires assets to be sorted.
+///
+/// Assets are displayed and parsed as `native` or `CODE:ISSUER`. See
+/// [`Asset::to_txrep`] and [`Asset::to_caip19`] for the other supported forms.
+/// Displaying an asset whose code is not valid, see [`Asset::is_valid`], in
+/// any form returns an error rather than printing a code that cannot be parsed
+/// back.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::SerializeDisplay, serde_with::DeserializeFromStr)
+)]
+pub enum Asset {
+    Native,
+    AlphaNum4(AlphaNum4),
+    AlphaNum12(AlphaNum12),
+}
+
+/// Stores an issued asset with a code of 1 to 4 characters.
+///
+/// The code is zero padded on the right. Use [`AlphaNum4::new`] to construct a
+/// value with a code that is checked against the protocol's rules.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct AlphaNum4 {
+    pub code: [u8; 4],
+    pub issuer: ed25519::PublicKey,
+}
+
+/// Stores an issued asset with a code of 5 to 12 characters.
+///
+/// The code is zero padded on the right. Use [`AlphaNum12::new`] to construct a
+/// value with a code that is checked against the protocol's rules.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct AlphaNum12 {
+    pub code: [u8; 12],
+    pub issuer: ed25519::PublicKey,
+}
+
+/// Checks that the zero padded code contains `min_len` or more ASCII
+/// alphanumeric characters, followed only by zero padding.
+fn is_valid_code(code: &[u8], min_len: usize) -> bool {
+    let len = code.iter().position(|b| *b == 0).unwrap_or(code.len());
+    let (chars, padding) = code.split_at(len);
+    len >= min_len && chars.iter().all(u8::is_ascii_alphanumeric) && padding.iter().all(|b| *b == 0)
+}
+
+/// Returns the code with the zero padding removed, or an empty code if it is
+/// not UTF-8.
+fn trim_code(code: &[u8]) -> &str {
+    let len = code.iter().position(|b| *b == 0).unwrap_or(code.len());
+    core::str::from_utf8(&code[..len]).unwrap_or_default()
+}
+
+/// Copies the code into a zero padded array.
+///
+/// The code must be only ASCII alphanumeric characters, so that zero bytes in
+/// it cannot be mistaken for padding.
+fn pad_code<const N: usize>(code: &str) -> Result<[u8; N], DecodeError> {
+    let code = code.as_bytes();
+    if code.len() > N || !code.iter().all(u8::is_ascii_alphanumeric) {
+        return Err(DecodeError::Invalid);
+    }
+    let mut padded = [0; N];
+    padded[..code.len()].copy_from_slice(code);
+    Ok(padded)
+}
+
+impl AlphaNum4 {
+    /// Creates an asset with a code of 1 to 4 ASCII alphanumeric characters.
+    ///
+    /// ### Errors
+    ///
+    /// If the code is empty, longer than 4 characters, or contains characters
+    /// other than `a-z`, `A-Z` and `0-9`.
+    pub fn new(code: &str, issuer: ed25519::PublicKey) -> Result<Self, DecodeError> {
+        let a = Self {
+            code: pad_code(code)?,
+            issuer,
+        };
+        if a.is_valid() {
+            Ok(a)
+        } else {
+            Err(DecodeError::Invalid)
+        }
+    }
+
+    /// Returns the code with the zero padding removed.
+    pub fn code(&self) -> &str {
+        trim_code(&self.code)
+    }
+
+    /// Returns true if the code satisfies the protocol's rules.
+    pub fn is_valid(&self) -> bool {
+        is_valid_code(&self.code, 1)
+    }
+}
+
+impl AlphaNum12 {
+    /// Creates an asset with a code of 5 to 12 ASCII alphanumeric characters.
+    ///
+    /// ### Errors
+    ///
+    /// If the code is shorter than 5 characters, longer than 12 characters, or
+    /// contains characters other than `a-z`, `A-Z` and `0-9`.
+    pub fn new(code: &str, issuer: ed25519::PublicKey) -> Result<Self, DecodeError> {
+        let a = Self {
+            code: pad_code(code)?,
+            issuer,
+        };
+        if a.is_valid() {
+            Ok(a)
+        } else {
+            Err(DecodeError::Invalid)
+        }
+    }
+
+    /// Returns the code with the zero padding removed.
+    pub fn code(&self) -> &str {
+        trim_code(&self.code)
+    }
+
+    /// Returns true if the code satisfies the protocol's rules.
+    pub fn is_valid(&self) -> bool {
+        is_valid_code(&self.code, 5)
+    }
+}
+
+impl Asset {
+    // XDR: 4 type + 12 code + 4 account id type + 32 ed25519 = 52
+    #[cfg(feature = "sha2")]
+    pub(crate) const MAX_XDR_LEN: usize = 4 + 12 + 4 + 32;
+
+    const NATIVE: &'static str = "native";
+    const TXREP_NATIVE: &'static str = "XLM";
+    const CAIP19_NATIVE: &'static str = "slip44:148";
+    const CAIP19_ASSET_PREFIX: &'static str = "asset:";
+
+    /// Creates an issued asset, choosing the variant from the length of the
+    /// code.
+    ///
+    /// ### Errors
+    ///
+    /// If the code does not satisfy the protocol's rules.
+    pub fn new(code: &str, issuer: ed25519::PublicKey) -> Result<Self, DecodeError> {
+        match code.len() {
+            0..=4 => Ok(Self::AlphaNum4(AlphaNum4::new(code, issuer)?)),
+            _ => Ok(Self::AlphaNum12(AlphaNum12::new(code, issuer)?)),
+        }
+    }
+
+    /// Returns the code of an issued asset, or `None` for the native asset.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Self::Native => None,
+            Self::AlphaNum4(a) => Some(a.code()),
+            Self::AlphaNum12(a) => Some(a.code()),
+        }
+    }
+
+    /// Returns the issuer of an issued asset, or `None` for the native asset.
+    pub fn issuer(&self) -> Option<&ed25519::PublicKey> {
+        match self {
+            Self::Native => None,
+            Self::AlphaNum4(a) => Some(&a.issuer),
+            Self::AlphaNum12(a) => Some(&a.issuer),
+        }
+    }
+
+    /// Returns true if the asset code satisfies the protocol's rules.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Native => true,
+            Self::AlphaNum4(a) => a.is_valid(),
+            Self::AlphaNum12(a) => a.is_valid(),
+        }
+    }
+
+    /// Appends the XDR encoding of the asset to the buffer.
+    #[cfg(feature = "sha2")]
+    pub(crate) fn write_xdr<const N: usize>(&self, buf: &mut Vec<u8, N>) -> Result<(), ()> {
+        match self {
+            Self::Native => buf.extend_from_slice(&0u32.to_be_bytes()),
+            Self::AlphaNum4(a) => {
+                buf.extend_from_slice(&1u32.to_be_bytes())?;
+                buf.extend_from_slice(&a.code)?;
+                buf.extend_from_slice(&0u32.to_be_bytes())?;
+                buf.extend_from_slice(&a.issuer.0)
+            }
+            Self::AlphaNum12(a) => {
+                buf.extend_from_slice(&2u32.to_be_bytes())?;
+                buf.extend_from_slice(&a.code)?;
+                buf.extend_from_slice(&0u32.to_be_bytes())?;
+                buf.extend_from_slice(&a.issuer.0)
+            }
+        }
+    }
+
+    /// Parses an issued asset in the `CODE:ISSUER` form.
+    fn from_code_issuer(s: &str, separator: char) -> Result<Self, DecodeError> {
+        let (code, issuer) = s.split_once(separator).ok_or(DecodeError::Invalid)?;
+        Self::new(code, ed25519::PublicKey::from_string(issuer)?)
+    }
+
+    /// Parses an asset in the `native` or `CODE:ISSUER` form.
+    pub fn from_string(s: &str) -> Result<Self, DecodeError> {
+        if s == Self::NATIVE {
+            return Ok(Self::Native);
+        }
+        Self::from_code_issuer(s, ':')
+    }
+
+    /// Parses an asset in the SEP-11 txrep form, `XLM` or `CODE:ISSUER`.
+    pub fn from_txrep(s: &str) -> Result<Self, DecodeError> {
+        if s == Self::TXREP_NATIVE {
+            return Ok(Self::Native);
+        }
+        Self::from_code_issuer(s, ':')
+    }
+
+    /// Returns a value that displays the asset in the SEP-11 txrep form, `XLM`
+    /// or `CODE:ISSUER`.
+    pub fn to_txrep(&self) -> AssetTxrep<'_> {
+        AssetTxrep(self)
+    }
+
+    /// Parses an asset in the CAIP-19 form, `stellar:<network>/slip44:148` or
+    /// `stellar:<network>/asset:CODE-ISSUER`, returning the network reference
+    /// and the asset.
+    pub fn from_caip19(s: &str) -> Result<(&str, Self), DecodeError> {
+        let s = s.strip_prefix("stellar:").ok_or(DecodeError::Invalid)?;
+        let (network, asset) = s.split_once('/').ok_or(DecodeError::Invalid)?;
+        if network.is_empty() {
+            return Err(DecodeError::Invalid);
+        }
+        if asset == Self::CAIP19_NATIVE {
+            return Ok((network, Self::Native));
+        }
+        let asset = asset
+            .strip_prefix(Self::CAIP19_ASSET_PREFIX)
+            .ok_or(DecodeError::Invalid)?;
+        Ok((network, Self::from_code_issuer(asset, '-')?))
+    }
+
+    /// Returns a value that displays the asset in the CAIP-19 form for the
+    /// network reference, e.g. `pubnet` or `testnet`.
+    pub fn to_caip19<'a>(&'a self, network: &'a str) -> AssetCaip19<'a> {
+        AssetCaip19 {
+            network,
+            asset: self,
+        }
+    }
+}
+
+impl Display for Asset {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.is_valid() {
+            return Err(core::fmt::Error);
+        }
+        match self {
+            Self::Native => f.write_str(Self::NATIVE),
+            Self::AlphaNum4(a) => write!(f, "{}:{}", a.code(), a.issuer),
+            Self::AlphaNum12(a) => write!(f, "{}:{}", a.code(), a.issuer),
+        }
+    }
+}
+
+impl FromStr for Asset {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Asset::from_string(s)
+    }
+}
+
+/// Displays an asset in the SEP-11 txrep form. Returned by [`Asset::to_txrep`].
+#[derive(Clone, Copy, Debug)]
+pub struct AssetTxrep<'a>(&'a Asset);
+
+impl Display for AssetTxrep<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Asset::Native => f.write_str(Asset::TXREP_NATIVE),
+            asset => write!(f, "{asset}"),
+        }
+    }
+}
+
+/// Displays an asset in the CAIP-19 form. Returned by [`Asset::to_caip19`].
+#[derive(Clone, Copy, Debug)]
+pub struct AssetCaip19<'a> {
+    network: &'a str,
+    asset: &'a Asset,
+}
+
+impl Display for AssetCaip19<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.asset.is_valid() {
+            return Err(core::fmt::Error);
+        }
+        write!(f, "stellar:{}/", self.network)?;
+        match self.asset {
+            Asset::Native => f.write_str(Asset::CAIP19_NATIVE),
+            Asset::AlphaNum4(a) => {
+                write!(f, "{}{}-{}", Asset::CAIP19_ASSET_PREFIX, a.code(), a.issuer)
+            }
+            Asset::AlphaNum12(a) => {
+                write!(f, "{}{}-{}", Asset::CAIP19_ASSET_PREFIX, a.code(), a.issuer)
+            }
+        }
+    }
+}
@@ -6,7 +6,24 @@ fn main() {
     if let Err(e) = cli::run(env::args_os()) {
         match e {
             cli::Error::Clap(e) => e.exit(),
-            cli::Error::Decode(_) | cli::Error::Encode(_) => {
+            cli::Error::Decode(_)
+            | cli::Error::Encode(_)
+            | cli::Error::LiquidityPoolId(_)
+            | cli::Error::Keystore(_)
+            | cli::Error::Keys(_)
+            | cli::Error::Seal(_)
+            | cli::Error::Open(_)
+            | cli::Error::SignMessage(_)
+            | cli::Error::VerifyMessage(_)
+            | cli::Error::SignedPayload(_)
+            | cli::Error::Shares(_)
+            | cli::Error::Import(_)
+            | cli::Error::Export(_)
+            | cli::Error::Mux(_)
+            | cli::Error::Demux(_)
+            | cli::Error::LedgerKey(_)
+            | cli::Error::Lookalike(_)
+            | cli::Error::AddressBook(_) => {
                 Error::raw(clap::error::ErrorKind::ValueValidation, e).exit()
             }
         }
@@ -0,0 +1,148 @@
+use core::{fmt::Display, str::FromStr};
+
+use heapless::String;
+
+use crate::{error::DecodeError, Strkey};
+
+/// Stores a CAIP-2 chain ID in the `stellar` namespace, `stellar:<network>`,
+/// e.g. `stellar:pubnet`.
+///
+/// The network reference is 1 to 32 characters of `a-z`, `A-Z`, `0-9`, `-`
+/// and `_`, as required by CAIP-2.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::SerializeDisplay, serde_with::DeserializeFromStr)
+)]
+pub struct Caip2 {
+    reference: String<{ Caip2::MAX_REFERENCE_LEN }>,
+}
+
+/// Stores a CAIP-10 account ID in the `stellar` namespace,
+/// `stellar:<network>:<address>`, e.g. `stellar:pubnet:G…`.
+///
+/// The address is a `G` account, `M` muxed account or `C` contract strkey.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::SerializeDisplay, serde_with::DeserializeFromStr)
+)]
+pub struct Caip10 {
+    network: Caip2,
+    address: Strkey,
+}
+
+impl Caip2 {
+    const NAMESPACE: &'static str = "stellar";
+    const MAX_REFERENCE_LEN: usize = 32;
+
+    /// The network reference of the public network.
+    pub const PUBNET: &'static str = "pubnet";
+    /// The network reference of the test network.
+    pub const TESTNET: &'static str = "testnet";
+
+    /// Creates a chain ID for the network reference.
+    ///
+    /// ### Errors
+    ///
+    /// If the reference is empty, longer than 32 characters, or contains
+    /// characters other than `a-z`, `A-Z`, `0-9`, `-` and `_`.
+    pub fn new(reference: &str) -> Result<Self, DecodeError> {
+        let valid = reference
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+        if !valid || reference.is_empty() {
+            return Err(DecodeError::Invalid);
+        }
+        Ok(Self {
+            reference: String::try_from(reference).map_err(|()| DecodeError::Invalid)?,
+        })
+    }
+
+    /// Returns the chain ID of the public network, `stellar:pubnet`.
+    pub fn pubnet() -> Self {
+        // Safety: unwrap cannot fail because the reference is valid.
+        Self::new(Self::PUBNET).unwrap()
+    }
+
+    /// Returns the chain ID of the test network, `stellar:testnet`.
+    pub fn testnet() -> Self {
+        // Safety: unwrap cannot fail because the reference is valid.
+        Self::new(Self::TESTNET).unwrap()
+    }
+
+    /// Returns the network reference, e.g. `pubnet`.
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
+    /// Parses a chain ID in the `stellar:<network>` form.
+    pub fn from_string(s: &str) -> Result<Self, DecodeError> {
+        let reference = s
+            .strip_prefix(Self::NAMESPACE)
+            .and_then(|s| s.strip_prefix(':'))
+            .ok_or(DecodeError::Invalid)?;
+        Self::new(reference)
+    }
+}
+
+impl Display for Caip2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", Self::NAMESPACE, self.reference)
+    }
+}
+
+impl FromStr for Caip2 {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Caip2::from_string(s)
+    }
+}
+
+impl Caip10 {
+    /// Creates an account ID for the address on the network.
+    ///
+    /// ### Errors
+    ///
+    /// [`DecodeError::UnexpectedKind`] if the address is not a `G`, `M` or
+    /// `C` strkey.
+    pub fn new(network: Caip2, address: Strkey) -> Result<Self, DecodeError> {
+        match address {
+            Strkey::PublicKeyEd25519(_) | Strkey::MuxedAccountEd25519(_) | Strkey::Contract(_) => {
+                Ok(Self { network, address })
+            }
+            _ => Err(DecodeError::UnexpectedKind),
+        }
+    }
+
+    /// Returns the chain ID of the network.
+    pub fn network(&self) -> &Caip2 {
+        &self.network
+    }
+
+    /// Returns the address.
+    pub fn address(&self) -> &Strkey {
+        &self.address
+    }
+
+    /// Parses an account ID in the `stellar:<network>:<address>` form.
+    pub fn from_string(s: &str) -> Result<Self, DecodeError> {
+        let (network, address) = s.rsplit_once(':').ok_or(DecodeError::Invalid)?;
+        Self::new(Caip2::from_string(network)?, Strkey::from_string(address)?)
+    }
+}
+
+impl Display for Caip10 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.network, self.address)
+    }
+}
+
+impl FromStr for Caip10 {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Caip10::from_string(s)
+    }
+}
@@ -0,0 +1,402 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::{Args, Subcommand};
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::{cli::keys, DecodeError, Similarity, Strkey};
+
+/// Environment variable that overrides the path of the default address book.
+const ADDRESS_BOOK_ENV: &str = "STELLAR_STRKEY_ADDRESS_BOOK";
+
+#[derive(Debug)]
+pub enum Error {
+    NoAddressBook,
+    Io(PathBuf, io::Error),
+    Json(PathBuf, serde_json::Error),
+    Keys(keys::Error),
+    Decode(String, DecodeError),
+    Entry(String),
+    Exists(String),
+    Labeled(String, String),
+    Lookalike(String, String),
+    Invalid(usize),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::NoAddressBook => f.write_fmt(format_args!(
+                "no address book, set the {ADDRESS_BOOK_ENV} or HOME environment variable"
+            )),
+            Error::Io(path, e) => f.write_fmt(format_args!("{}: {e}", path.display())),
+            Error::Json(path, e) => f.write_fmt(format_args!("{}: {e}", path.display())),
+            Error::Keys(e) => f.write_fmt(format_args!("{e}")),
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Entry(msg) => f.write_str(msg),
+            Error::Exists(label) => f.write_fmt(format_args!(
+                "label {label:?} already exists, use --force to replace it"
+            )),
+            Error::Labeled(address, label) => f.write_fmt(format_args!(
+                "{address} is already labeled {label:?}, use --force to add it again"
+            )),
+            Error::Lookalike(address, label) => f.write_fmt(format_args!(
+                "{address} looks like the address labeled {label:?} without being it, and may be an address poisoning attempt, use --force to add it anyway"
+            )),
+            Error::Invalid(problems) => {
+                f.write_fmt(format_args!("the address book has {problems} problems"))
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Labeled addresses, stored as a JSON object of labels to strkeys, e.g.
+/// `{"treasury": "G…"}`.
+///
+/// Every strkey is validated when the address book is loaded, and private
+/// keys are rejected, since an address book is shared more freely than keys.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct AddressBook {
+    entries: BTreeMap<String, Strkey>,
+}
+
+impl AddressBook {
+    /// Parses an address book from JSON.
+    ///
+    /// ### Errors
+    ///
+    /// If the JSON is not an object of labels to strkeys, a label is invalid
+    /// or appears twice, or a strkey is a private key.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the address book as pretty JSON, with labels sorted.
+    pub fn to_json(&self) -> String {
+        // Safety: unwrap cannot fail because the address book serializes as a
+        // map of string labels to strkeys, which serialize as strings.
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Loads the address book from the file.
+    ///
+    /// ### Errors
+    ///
+    /// If the file cannot be read or is not a valid address book.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let json = fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        Self::from_json(&json).map_err(|e| Error::Json(path.to_path_buf(), e))
+    }
+
+    /// Loads the address book from the file, or returns an empty address
+    /// book if the file does not exist.
+    ///
+    /// ### Errors
+    ///
+    /// If the file exists and cannot be read or is not a valid address book.
+    pub fn load_or_default(path: &Path) -> Result<Self, Error> {
+        match Self::load(path) {
+            Err(Error::Io(_, e)) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            result => result,
+        }
+    }
+
+    /// Writes the address book to the file, creating its directory.
+    ///
+    /// The address book is written to a temporary file next to the file and
+    /// renamed over it, so that the file is never left partially written.
+    ///
+    /// ### Errors
+    ///
+    /// If the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|e| Error::Io(dir.to_path_buf(), e))?;
+        }
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        let write = |tmp: &Path| -> io::Result<()> {
+            let mut file = fs::File::create(tmp)?;
+            file.write_all(format!("{}\n", self.to_json()).as_bytes())?;
+            file.sync_all()
+        };
+        if let Err(e) = write(&tmp) {
+            let _ = fs::remove_file(&tmp);
+            return Err(Error::Io(tmp, e));
+        }
+        fs::rename(&tmp, path).map_err(|e| {
+            let _ = fs::remove_file(&tmp);
+            Error::Io(path.to_path_buf(), e)
+        })
+    }
+
+    /// Returns the labels and strkeys, sorted by label.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &Strkey)> {
+        self.entries
+            .iter()
+            .map(|(label, strkey)| (label.as_str(), strkey))
+    }
+
+    /// Returns the strkey with the label.
+    pub fn get(&self, label: &str) -> Option<&Strkey> {
+        self.entries.get(label)
+    }
+
+    /// Returns the first label, in sorted order, of the strkey.
+    pub fn label(&self, strkey: &Strkey) -> Option<&str> {
+        self.entries()
+            .find(|(_, s)| *s == strkey)
+            .map(|(label, _)| label)
+    }
+
+    /// Sets the strkey of the label, returning the strkey it replaced.
+    ///
+    /// ### Errors
+    ///
+    /// If the label is invalid, or the strkey is a private key.
+    pub fn insert(&mut self, label: &str, strkey: Strkey) -> Result<Option<Strkey>, Error> {
+        check_entry(label, &strkey).map_err(Error::Entry)?;
+        Ok(self.entries.insert(label.to_string(), strkey))
+    }
+
+    /// Returns a description of each problem with the address book: strkeys
+    /// labeled more than once, and strkeys that look like each other without
+    /// being the same, as in address poisoning.
+    pub fn problems(&self) -> Vec<String> {
+        let entries = self.entries().collect::<Vec<_>>();
+        let mut problems = Vec::new();
+        for (i, (label, strkey)) in entries.iter().enumerate() {
+            for (other_label, other) in &entries[i + 1..] {
+                if strkey == other {
+                    problems.push(format!(
+                        "{label:?} and {other_label:?} are the same address {strkey}"
+                    ));
+                } else if let Some(similarity) =
+                    strkey.lookalike(other, Similarity::DEFAULT_MIN_MATCH)
+                {
+                    problems.push(format!(
+                        "{label:?} {strkey} and {other_label:?} {other} look alike, {} leading and {} trailing characters match",
+                        similarity.head, similarity.tail
+                    ));
+                }
+            }
+        }
+        problems
+    }
+
+    /// Returns the strkey displayed with its label after a tab, or the
+    /// strkey alone if it has no label.
+    pub fn annotate(&self, strkey: &Strkey) -> String {
+        match self.label(strkey) {
+            Some(label) => format!("{strkey}\t{label}"),
+            None => format!("{strkey}"),
+        }
+    }
+}
+
+/// Checks a label is not empty, has no leading or trailing whitespace or
+/// control characters, so that it prints on one line after a tab, and that
+/// the strkey is not a private key.
+fn check_entry(label: &str, strkey: &Strkey) -> Result<(), String> {
+    if label.is_empty() || label.trim() != label || label.chars().any(char::is_control) {
+        return Err(format!(
+            "invalid label {label:?}, labels must not be empty, have leading or trailing whitespace, or contain control characters"
+        ));
+    }
+    if let Strkey::PrivateKeyEd25519(_) = strkey {
+        return Err(format!("label {label:?} is a private key, not an address"));
+    }
+    Ok(())
+}
+
+impl<'de> Deserialize<'de> for AddressBook {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AddressBookVisitor;
+
+        impl<'de> Visitor<'de> for AddressBookVisitor {
+            type Value = AddressBook;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object of labels to strkeys")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut book = AddressBook::default();
+                while let Some((label, strkey)) = map.next_entry::<String, Strkey>()? {
+                    check_entry(&label, &strkey).map_err(de::Error::custom)?;
+                    if book.entries.insert(label.clone(), strkey).is_some() {
+                        return Err(de::Error::custom(format!("duplicate label {label:?}")));
+                    }
+                }
+                Ok(book)
+            }
+        }
+
+        deserializer.deserialize_map(AddressBookVisitor)
+    }
+}
+
+/// Returns the path of the default address book.
+fn default_path() -> Result<PathBuf, Error> {
+    if let Some(path) = std::env::var_os(ADDRESS_BOOK_ENV) {
+        return Ok(PathBuf::from(path));
+    }
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        .ok_or(Error::NoAddressBook)?;
+    Ok(config.join("stellar-strkey").join("address-book.json"))
+}
+
+/// Arguments of commands that print the label of addresses in an address
+/// book next to them.
+#[derive(Args, Debug, Clone)]
+pub struct LabelArgs {
+    /// Print the labels of addresses in the address book next to them, from
+    /// the file if given as --address-book=FILE, otherwise from the default
+    /// address book
+    #[arg(long, value_name = "FILE", num_args = 0..=1, require_equals = true)]
+    address_book: Option<Option<PathBuf>>,
+}
+
+impl LabelArgs {
+    /// Loads the address book if labels were requested. The default address
+    /// book is empty until an address is added to it.
+    pub(crate) fn load(&self) -> Result<Option<AddressBook>, Error> {
+        match &self.address_book {
+            None => Ok(None),
+            Some(Some(path)) => AddressBook::load(path).map(Some),
+            Some(None) => AddressBook::load_or_default(&default_path()?).map(Some),
+        }
+    }
+}
+
+/// The address book file that an address book command manages.
+#[derive(Args, Debug, Clone)]
+pub struct PathArgs {
+    /// Address book file, otherwise the default address book in the config
+    /// directory, or the STELLAR_STRKEY_ADDRESS_BOOK environment variable
+    #[arg(long, value_name = "FILE")]
+    address_book: Option<PathBuf>,
+}
+
+impl PathArgs {
+    fn path(&self) -> Result<PathBuf, Error> {
+        match &self.address_book {
+            Some(path) => Ok(path.clone()),
+            None => default_path(),
+        }
+    }
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Cmd {
+    /// Label an address, refusing labels that exist and addresses that are
+    /// labeled or look like labeled addresses unless forced
+    Add(AddCmd),
+    /// List labels and their addresses
+    #[command(visible_alias = "ls")]
+    List(ListCmd),
+    /// Check the address book for addresses labeled more than once or that
+    /// look like each other, exiting with an error if there are any
+    Check(ListCmd),
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct AddCmd {
+    /// Label of the address
+    #[arg()]
+    label: String,
+    /// Strkey to label, or @name of a stored identity
+    #[arg()]
+    address: String,
+    /// Replace the label if it exists, and add the address even if it is
+    /// labeled or looks like a labeled address
+    #[arg(long)]
+    force: bool,
+    #[command(flatten)]
+    path: PathArgs,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct ListCmd {
+    #[command(flatten)]
+    path: PathArgs,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        match self {
+            Cmd::Add(c) => c.run(),
+            Cmd::List(c) => c.run_list(),
+            Cmd::Check(c) => c.run_check(),
+        }
+    }
+}
+
+impl AddCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let path = self.path.path()?;
+        let mut book = AddressBook::load_or_default(&path)?;
+        let address = keys::resolve(&self.address).map_err(Error::Keys)?;
+        let strkey =
+            Strkey::from_string(&address).map_err(|e| Error::Decode(address.clone(), e))?;
+        if !self.force {
+            if book.get(&self.label).is_some() {
+                return Err(Error::Exists(self.label.clone()));
+            }
+            if let Some(label) = book.label(&strkey) {
+                return Err(Error::Labeled(address, label.to_string()));
+            }
+            let lookalike = book
+                .entries()
+                .find(|(_, known)| {
+                    strkey
+                        .lookalike(known, Similarity::DEFAULT_MIN_MATCH)
+                        .is_some()
+                })
+                .map(|(label, _)| label.to_string());
+            if let Some(label) = lookalike {
+                return Err(Error::Lookalike(address, label));
+            }
+        }
+        book.insert(&self.label, strkey)?;
+        book.save(&path)
+    }
+}
+
+impl ListCmd {
+    pub fn run_list(&self) -> Result<(), Error> {
+        let book = AddressBook::load_or_default(&self.path.path()?)?;
+        for (label, strkey) in book.entries() {
+            println!("{label}\t{strkey}");
+        }
+        Ok(())
+    }
+
+    pub fn run_check(&self) -> Result<(), Error> {
+        let book = AddressBook::load(&self.path.path()?)?;
+        let problems = book.problems();
+        if problems.is_empty() {
+            println!("ok");
+            return Ok(());
+        }
+        for problem in &problems {
+            println!("{problem}");
+        }
+        Err(Error::Invalid(problems.len()))
+    }
+}
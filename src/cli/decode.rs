@@ -1,17 +1,26 @@
-use std::str::FromStr;
-
-use crate::{DecodeError, Decoded, Strkey};
-use clap::Args;
+use crate::{
+    cli::{address_book, keys, well_known::WellKnownArgs},
+    ed25519, DecodeError, Decoded, HexKind, Strkey,
+};
+use clap::{Args, ValueEnum};
 
 #[derive(Debug)]
 pub enum Error {
     Decode(String, DecodeError),
+    Keys(keys::Error),
+    NoHorizonForm(String),
+    AddressBook(address_book::Error),
 }
 
 impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Keys(inner) => f.write_fmt(format_args!("{inner}")),
+            Error::NoHorizonForm(s) => f.write_fmt(format_args!(
+                "{s:?} has no Horizon hex form, only claimable balances and liquidity pools do"
+            )),
+            Error::AddressBook(inner) => f.write_fmt(format_args!("{inner}")),
         }
     }
 }
@@ -21,17 +30,115 @@ impl core::error::Error for Error {}
 #[derive(Args, Debug, Clone)]
 #[command()]
 pub struct Cmd {
-    /// Strkey to decode
+    /// Strkey to decode, @name of a stored identity, hex, 0x-prefixed hex,
+    /// base64 XDR AccountID, MuxedAccount or SCAddress, Horizon hex claimable
+    /// balance or liquidity pool ID, or CAIP-10 account ID
     #[arg()]
     strkey: String,
+    /// Kind of key that hex is decoded as, required for 64 characters of hex,
+    /// e.g. liquidity-pool for a Horizon liquidity pool ID
+    #[arg(long, value_enum)]
+    kind: Option<Kind>,
+    /// Reject ed25519 public keys that are not valid, canonical and not small
+    /// order curve points
+    #[arg(long)]
+    strict: bool,
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    output: Output,
+    #[command(flatten)]
+    labels: address_book::LabelArgs,
+    #[command(flatten)]
+    well_known: WellKnownArgs,
+}
+
+#[derive(Clone, Debug, Default, ValueEnum)]
+pub enum Output {
+    /// The decoded strkey as JSON, with --address-book a label field if the
+    /// address book labels it, and with --well-known a well_known field if it
+    /// is well-known
+    #[default]
+    Json,
+    /// The strkey
+    Strkey,
+    /// The hex claimable balance or liquidity pool ID, as reported by Horizon
+    Horizon,
+    /// The format the input was detected as
+    Format,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Kind {
+    PublicKey,
+    PrivateKey,
+    PreAuthTx,
+    HashX,
+    Contract,
+    LiquidityPool,
+    ClaimableBalance,
+}
+
+impl From<Kind> for HexKind {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::PublicKey => HexKind::PublicKey,
+            Kind::PrivateKey => HexKind::PrivateKey,
+            Kind::PreAuthTx => HexKind::PreAuthTx,
+            Kind::HashX => HexKind::HashX,
+            Kind::Contract => HexKind::Contract,
+            Kind::LiquidityPool => HexKind::LiquidityPool,
+            Kind::ClaimableBalance => HexKind::ClaimableBalance,
+        }
+    }
 }
 
 impl Cmd {
     pub fn run(&self) -> Result<(), Error> {
-        let strkey =
-            Strkey::from_str(&self.strkey).map_err(|e| Error::Decode(self.strkey.clone(), e))?;
-        let json = serde_json::to_string_pretty(&Decoded(&strkey)).unwrap();
-        println!("{json}");
+        let s = keys::resolve(&self.strkey).map_err(Error::Keys)?;
+        let (strkey, format) = Strkey::detect(&s, self.kind.map(HexKind::from))
+            .map_err(|e| Error::Decode(s.clone(), e))?;
+        if self.strict {
+            let ed25519 = match &strkey {
+                Strkey::PublicKeyEd25519(k) => Some(*k),
+                Strkey::MuxedAccountEd25519(m) => Some(ed25519::PublicKey(m.ed25519)),
+                Strkey::SignedPayloadEd25519(p) => Some(ed25519::PublicKey(p.ed25519)),
+                _ => None,
+            };
+            if let Some(key) = ed25519 {
+                key.check_point().map_err(|e| Error::Decode(s.clone(), e))?;
+            }
+        }
+        let book = self.labels.load().map_err(Error::AddressBook)?;
+        match self.output {
+            Output::Json => {
+                let mut json = serde_json::to_value(Decoded(&strkey)).unwrap();
+                if let Some(object) = json.as_object_mut() {
+                    if let Some(label) = book.as_ref().and_then(|book| book.label(&strkey)) {
+                        object.insert("label".to_string(), label.into());
+                    }
+                    if let Some(well_known) = self.well_known.lookup(&strkey) {
+                        object.insert("well_known".to_string(), well_known.to_string().into());
+                    }
+                }
+                println!("{}", serde_json::to_string_pretty(&json).unwrap());
+            }
+            Output::Strkey => match self.well_known.annotation(book.as_ref(), &strkey) {
+                Some(annotation) => println!("{strkey}\t{annotation}"),
+                None => println!("{strkey}"),
+            },
+            Output::Horizon => {
+                let hex = match &strkey {
+                    Strkey::ClaimableBalance(balance) => balance.to_horizon_hex().to_string(),
+                    Strkey::LiquidityPool(pool) => pool.to_horizon_hex().to_string(),
+                    _ => return Err(Error::NoHorizonForm(s)),
+                };
+                match self.well_known.annotation(book.as_ref(), &strkey) {
+                    Some(annotation) => println!("{hex}\t{annotation}"),
+                    None => println!("{hex}"),
+                }
+            }
+            Output::Format => println!("{format}"),
+        }
         Ok(())
     }
 }
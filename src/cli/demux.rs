@@ -0,0 +1,124 @@
+use clap::{Args, ValueEnum};
+use serde::Deserialize;
+
+use crate::{
+    cli::{
+        address_book::{self, LabelArgs},
+        keys,
+        mux::{self, csv_fields, Input, InputArgs, Record},
+        well_known::WellKnownArgs,
+    },
+    ed25519, DecodeError, Strkey,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Decode(String, DecodeError),
+    Input(mux::Error),
+    Json(usize, serde_json::Error),
+    AddressBook(address_book::Error),
+    Keys(keys::Error),
+    Line(usize, Box<Error>),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Input(inner) => f.write_fmt(format_args!("{inner}")),
+            Error::Json(line, e) => f.write_fmt(format_args!("line {line}: {e}")),
+            Error::AddressBook(inner) => f.write_fmt(format_args!("{inner}")),
+            Error::Keys(inner) => f.write_fmt(format_args!("{inner}")),
+            Error::Line(line, inner) => f.write_fmt(format_args!("line {line}: {inner}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// M strkeys or @names of stored identities to demultiplex, otherwise they are read from stdin
+    #[arg()]
+    muxed_accounts: Vec<String>,
+    #[command(flatten)]
+    input: InputArgs,
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    output: Output,
+    #[command(flatten)]
+    labels: LabelArgs,
+    #[command(flatten)]
+    well_known: WellKnownArgs,
+}
+
+#[derive(Clone, Debug, Default, ValueEnum)]
+pub enum Output {
+    /// Comma separated rows of account, ID and muxed account, and with
+    /// --address-book or --well-known their labels
+    #[default]
+    Csv,
+    /// JSON objects of account, ID and muxed account, and the labels of
+    /// those labeled, one per line
+    Json,
+}
+
+#[derive(Deserialize)]
+struct JsonInput {
+    muxed_account: String,
+}
+
+/// Parses an M strkey, resolving `@name` to the stored identity.
+fn parse_muxed_account(s: &str) -> Result<ed25519::MuxedAccount, Error> {
+    let resolved = keys::resolve(s).map_err(Error::Keys)?;
+    match Strkey::from_string(&resolved) {
+        Ok(Strkey::MuxedAccountEd25519(muxed)) => Ok(muxed),
+        Ok(_) => Err(Error::Decode(s.to_string(), DecodeError::UnexpectedKind)),
+        Err(e) => Err(Error::Decode(s.to_string(), e)),
+    }
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let muxed_accounts = if self.muxed_accounts.is_empty() {
+            self.read_muxed_accounts()?
+        } else {
+            self.muxed_accounts
+                .iter()
+                .map(|s| parse_muxed_account(s))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let book = self.labels.load().map_err(Error::AddressBook)?;
+        for muxed in muxed_accounts {
+            let record = Record::from(muxed).labeled(book.as_ref(), &self.well_known);
+            match self.output {
+                Output::Csv => println!(
+                    "{}",
+                    record.to_csv(book.is_some() || self.well_known.enabled())
+                ),
+                Output::Json => println!("{}", record.to_json()),
+            }
+        }
+        Ok(())
+    }
+
+    fn read_muxed_accounts(&self) -> Result<Vec<ed25519::MuxedAccount>, Error> {
+        self.input
+            .rows()
+            .map_err(Error::Input)?
+            .into_iter()
+            .map(|(line, row)| {
+                let muxed_account = match self.input.input {
+                    Input::Csv => csv_fields(&row)[0].to_string(),
+                    Input::Json => {
+                        let input: JsonInput =
+                            serde_json::from_str(&row).map_err(|e| Error::Json(line, e))?;
+                        input.muxed_account
+                    }
+                };
+                parse_muxed_account(&muxed_account).map_err(|e| Error::Line(line, Box::new(e)))
+            })
+            .collect()
+    }
+}
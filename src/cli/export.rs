@@ -0,0 +1,149 @@
+use std::io::{self, Write};
+
+use clap::{Args, ValueEnum};
+
+use crate::{
+    cli::{
+        keys::{self, Identity},
+        keystore::PasswordArgs,
+    },
+    ed25519,
+    key_formats::Jwk,
+    DecodeError, Strkey,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Keys(keys::Error),
+    Stdin(io::Error),
+    Stdout(io::Error),
+    Decode(String, DecodeError),
+    NotAPublicKey(String),
+    PublicKeyLibsodium,
+    PrivateKeyOnlyPublic,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Keys(e) => f.write_fmt(format_args!("{e}")),
+            Error::Stdin(e) => f.write_fmt(format_args!("reading stdin: {e}")),
+            Error::Stdout(e) => f.write_fmt(format_args!("writing stdout: {e}")),
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::NotAPublicKey(s) => f.write_fmt(format_args!("{s:?} is not a G strkey")),
+            Error::PublicKeyLibsodium => {
+                f.write_str("the libsodium format is only for private keys")
+            }
+            Error::PrivateKeyOnlyPublic => f.write_str(
+                "the multibase and did-key formats are only for public keys, use --public",
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Format {
+    /// PKCS#8 PEM for private keys, SubjectPublicKeyInfo PEM for public keys
+    Pem,
+    /// PKCS#8 DER for private keys, SubjectPublicKeyInfo DER for public keys
+    Der,
+    /// OpenSSH private key file, or ssh-ed25519 public key line
+    Openssh,
+    /// libsodium 64 byte secret key
+    Libsodium,
+    /// OKP JSON Web Key, with the private key for private keys
+    Jwk,
+    /// Multibase z6Mk… public key
+    Multibase,
+    /// did:key identifier of a public key
+    DidKey,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// G strkey, or @name of a stored identity, otherwise a secret is read from stdin
+    #[arg()]
+    key: Option<String>,
+    /// Format to export the key in
+    #[arg(long, value_enum)]
+    format: Format,
+    /// Export the public key of a private key
+    #[arg(long)]
+    public: bool,
+    /// Comment for OpenSSH keys
+    #[arg(long, default_value = "")]
+    comment: String,
+    #[command(flatten)]
+    password: PasswordArgs,
+}
+
+/// A key to export.
+enum Key {
+    Private(ed25519::PrivateKey),
+    Public(ed25519::PublicKey),
+}
+
+fn json(jwk: &Jwk) -> Vec<u8> {
+    // Safety: unwrap cannot fail because a Jwk contains only strings.
+    (serde_json::to_string_pretty(jwk).unwrap() + "\n").into_bytes()
+}
+
+impl Cmd {
+    fn key(&self) -> Result<Key, Error> {
+        match self.key.as_deref() {
+            None => {
+                let mut secret = String::new();
+                io::stdin().read_line(&mut secret).map_err(Error::Stdin)?;
+                ed25519::PrivateKey::from_string(secret.trim())
+                    .map(Key::Private)
+                    .map_err(|e| Error::Decode("secret".to_string(), e))
+            }
+            Some(s) => match s.strip_prefix('@') {
+                Some(name) => match keys::load(name).map_err(Error::Keys)? {
+                    Identity::Address(Strkey::PublicKeyEd25519(key)) => Ok(Key::Public(key)),
+                    Identity::Address(_) => Err(Error::NotAPublicKey(s.to_string())),
+                    identity if self.public => match identity.address() {
+                        Strkey::PublicKeyEd25519(key) => Ok(Key::Public(key)),
+                        _ => Err(Error::NotAPublicKey(s.to_string())),
+                    },
+                    _ => keys::load_secret(name, &self.password)
+                        .map(Key::Private)
+                        .map_err(Error::Keys),
+                },
+                None => ed25519::PublicKey::from_string(s)
+                    .map(Key::Public)
+                    .map_err(|e| Error::Decode(s.to_string(), e)),
+            },
+        }
+    }
+
+    pub fn run(&self) -> Result<(), Error> {
+        let key = match self.key()? {
+            Key::Private(key) if self.public => Key::Public(key.public_key()),
+            key => key,
+        };
+        let out = match (&self.format, key) {
+            (Format::Pem, Key::Private(key)) => key.to_pkcs8_pem().into_bytes(),
+            (Format::Pem, Key::Public(key)) => key.to_spki_pem().into_bytes(),
+            (Format::Der, Key::Private(key)) => key.to_pkcs8_der().to_vec(),
+            (Format::Der, Key::Public(key)) => key.to_spki_der().to_vec(),
+            (Format::Openssh, Key::Private(key)) => key.to_openssh(&self.comment).into_bytes(),
+            (Format::Openssh, Key::Public(key)) => {
+                (key.to_openssh(&self.comment) + "\n").into_bytes()
+            }
+            (Format::Libsodium, Key::Private(key)) => key.to_libsodium().to_vec(),
+            (Format::Libsodium, Key::Public(_)) => return Err(Error::PublicKeyLibsodium),
+            (Format::Jwk, Key::Private(key)) => json(&key.to_jwk()),
+            (Format::Jwk, Key::Public(key)) => json(&key.to_jwk()),
+            (Format::Multibase, Key::Public(key)) => (key.to_multibase() + "\n").into_bytes(),
+            (Format::DidKey, Key::Public(key)) => (key.to_did_key() + "\n").into_bytes(),
+            (Format::Multibase | Format::DidKey, Key::Private(_)) => {
+                return Err(Error::PrivateKeyOnlyPublic)
+            }
+        };
+        io::stdout().write_all(&out).map_err(Error::Stdout)
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli::keystore::{self, PasswordArgs},
+    ed25519,
+    keystore::{Keystore, ScryptParams},
+    DecodeError, Strkey,
+};
+
+/// Environment variable that overrides the directory identities are stored in.
+const KEYS_DIR_ENV: &str = "STELLAR_STRKEY_KEYS_DIR";
+
+#[derive(Debug)]
+pub enum Error {
+    NoKeysDir,
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+    Json(PathBuf, serde_json::Error),
+    InvalidName(String),
+    NotFound(String),
+    Exists(String),
+    Decode(String, DecodeError),
+    NotAnAddress(String),
+    NoSecret(String),
+    Keystore(keystore::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::NoKeysDir => f.write_fmt(format_args!(
+                "no keys directory, set the {KEYS_DIR_ENV} or HOME environment variable"
+            )),
+            Error::Io(path, e) => f.write_fmt(format_args!("{}: {e}", path.display())),
+            Error::Stdin(e) => f.write_fmt(format_args!("reading stdin: {e}")),
+            Error::Json(path, e) => f.write_fmt(format_args!("{}: {e}", path.display())),
+            Error::InvalidName(name) => f.write_fmt(format_args!(
+                "invalid identity name {name:?}, use only a-z, A-Z, 0-9, '-' and '_'"
+            )),
+            Error::NotFound(name) => f.write_fmt(format_args!("identity {name:?} not found")),
+            Error::Exists(name) => f.write_fmt(format_args!("identity {name:?} already exists")),
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::NotAnAddress(s) => f.write_fmt(format_args!("{s:?} is not a G, M or C address")),
+            Error::NoSecret(name) => f.write_fmt(format_args!(
+                "identity {name:?} is an address without a secret"
+            )),
+            Error::Keystore(e) => f.write_fmt(format_args!("{e}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// An identity stored in the keys directory, as `<name>.json`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Identity {
+    /// A watched `G`, `M` or `C` address.
+    Address(Strkey),
+    /// A plaintext private key.
+    Secret(ed25519::PrivateKey),
+    /// A private key encrypted in a keystore.
+    Keystore(Keystore),
+}
+
+impl Identity {
+    /// Returns the address of the identity.
+    pub fn address(&self) -> Strkey {
+        match self {
+            Identity::Address(strkey) => strkey.clone(),
+            Identity::Secret(key) => Strkey::PublicKeyEd25519(key.public_key()),
+            Identity::Keystore(keystore) => Strkey::PublicKeyEd25519(keystore.address),
+        }
+    }
+}
+
+/// Returns the directory identities are stored in.
+fn keys_dir() -> Result<PathBuf, Error> {
+    if let Some(dir) = std::env::var_os(KEYS_DIR_ENV) {
+        return Ok(PathBuf::from(dir));
+    }
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        .ok_or(Error::NoKeysDir)?;
+    Ok(config.join("stellar-strkey").join("keys"))
+}
+
+fn identity_path(dir: &Path, name: &str) -> Result<PathBuf, Error> {
+    let valid = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+    if !valid {
+        return Err(Error::InvalidName(name.to_string()));
+    }
+    Ok(dir.join(format!("{name}.json")))
+}
+
+/// Writes the identity file, readable only by the owner on unix systems.
+fn write_identity(path: &Path, json: &str) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+    writeln!(file, "{json}")
+}
+
+/// Loads the identity with the name from the keys directory.
+pub fn load(name: &str) -> Result<Identity, Error> {
+    let path = identity_path(&keys_dir()?, name)?;
+    let s = fs::read_to_string(&path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Error::NotFound(name.to_string()),
+        _ => Error::Io(path.clone(), e),
+    })?;
+    serde_json::from_str(&s).map_err(|e| Error::Json(path, e))
+}
+
+/// Loads the private key of the identity with the name, decrypting it with
+/// the password if it is encrypted.
+pub fn load_secret(name: &str, password: &PasswordArgs) -> Result<ed25519::PrivateKey, Error> {
+    match load(name)? {
+        Identity::Address(_) => Err(Error::NoSecret(name.to_string())),
+        Identity::Secret(key) => Ok(key),
+        Identity::Keystore(keystore) => {
+            let password = password.password().map_err(Error::Keystore)?;
+            keystore
+                .decrypt(password.as_bytes())
+                .map_err(|e| Error::Keystore(keystore::Error::Keystore(e)))
+        }
+    }
+}
+
+/// Resolves a strkey argument that may be an `@name` reference to a stored
+/// identity, returning the strkey string.
+pub fn resolve(s: &str) -> Result<String, Error> {
+    match s.strip_prefix('@') {
+        Some(name) => Ok(format!("{}", load(name)?.address())),
+        None => Ok(s.to_string()),
+    }
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Cmd {
+    /// Add an identity, either a watched address or a secret read from stdin
+    Add(AddCmd),
+    /// List identities and their addresses
+    #[command(visible_alias = "ls")]
+    List,
+    /// Print the secret of an identity, decrypting it if encrypted
+    Show(ShowCmd),
+    /// Remove an identity
+    Rm(NameCmd),
+    /// Print the address of an identity
+    Address(NameCmd),
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct AddCmd {
+    /// Name of the identity
+    #[arg()]
+    name: String,
+    /// G, M or C address to watch, otherwise a secret is read from stdin
+    #[arg(long)]
+    address: Option<String>,
+    /// Encrypt the secret in a keystore
+    #[arg(long, conflicts_with = "address")]
+    encrypt: bool,
+    #[command(flatten)]
+    password: PasswordArgs,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct ShowCmd {
+    /// Name of the identity
+    #[arg()]
+    name: String,
+    #[command(flatten)]
+    password: PasswordArgs,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct NameCmd {
+    /// Name of the identity
+    #[arg()]
+    name: String,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        match self {
+            Cmd::Add(c) => c.run(),
+            Cmd::List => Self::run_list(),
+            Cmd::Show(c) => c.run(),
+            Cmd::Rm(c) => c.run_rm(),
+            Cmd::Address(c) => c.run_address(),
+        }
+    }
+
+    fn run_list() -> Result<(), Error> {
+        let dir = keys_dir()?;
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(Error::Io(dir, e)),
+        };
+        let mut names = entries
+            .filter_map(Result::ok)
+            .filter_map(|e| {
+                let path = e.path();
+                match path.extension() {
+                    Some(ext) if ext == "json" => path
+                        .file_stem()
+                        .and_then(|s| s.to_str())
+                        .map(str::to_string),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            let identity = load(&name)?;
+            println!("{name}\t{}", identity.address());
+        }
+        Ok(())
+    }
+}
+
+impl AddCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let dir = keys_dir()?;
+        let path = identity_path(&dir, &self.name)?;
+        if path.exists() {
+            return Err(Error::Exists(self.name.clone()));
+        }
+        let identity = match &self.address {
+            Some(address) => {
+                let strkey =
+                    Strkey::from_str(address).map_err(|e| Error::Decode(address.clone(), e))?;
+                match strkey {
+                    Strkey::PublicKeyEd25519(_)
+                    | Strkey::MuxedAccountEd25519(_)
+                    | Strkey::Contract(_) => Identity::Address(strkey),
+                    _ => return Err(Error::NotAnAddress(address.clone())),
+                }
+            }
+            None => {
+                let mut secret = String::new();
+                io::stdin().read_line(&mut secret).map_err(Error::Stdin)?;
+                let secret = secret.trim();
+                let key = ed25519::PrivateKey::from_string(secret)
+                    .map_err(|e| Error::Decode("secret".to_string(), e))?;
+                if self.encrypt {
+                    let password = self.password.password().map_err(Error::Keystore)?;
+                    Identity::Keystore(
+                        Keystore::encrypt_with_cost(
+                            &key,
+                            password.as_bytes(),
+                            ScryptParams::DEFAULT_LOG_N,
+                            ScryptParams::DEFAULT_R,
+                            ScryptParams::DEFAULT_P,
+                        )
+                        .map_err(|e| Error::Keystore(keystore::Error::Keystore(e)))?,
+                    )
+                } else {
+                    Identity::Secret(key)
+                }
+            }
+        };
+        fs::create_dir_all(&dir).map_err(|e| Error::Io(dir.clone(), e))?;
+        let json =
+            serde_json::to_string_pretty(&identity).map_err(|e| Error::Json(path.clone(), e))?;
+        write_identity(&path, &json).map_err(|e| Error::Io(path.clone(), e))?;
+        println!("{}", identity.address());
+        Ok(())
+    }
+}
+
+impl ShowCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let key = load_secret(&self.name, &self.password)?;
+        println!("{key}");
+        Ok(())
+    }
+}
+
+impl NameCmd {
+    pub fn run_rm(&self) -> Result<(), Error> {
+        let path = identity_path(&keys_dir()?, &self.name)?;
+        fs::remove_file(&path).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => Error::NotFound(self.name.clone()),
+            _ => Error::Io(path.clone(), e),
+        })
+    }
+
+    pub fn run_address(&self) -> Result<(), Error> {
+        println!("{}", load(&self.name)?.address());
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use std::{fs, io, path::PathBuf};
+
+use clap::{Args, Subcommand};
+
+use crate::{
+    ed25519,
+    keystore::{self, Keystore, ScryptParams},
+    DecodeError,
+};
+
+/// Environment variable the keystore password is read from when no password
+/// file is given.
+const PASSWORD_ENV: &str = "STELLAR_STRKEY_PASSWORD";
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+    NoPassword,
+    Decode(DecodeError),
+    Json(serde_json::Error),
+    Keystore(keystore::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Io(path, e) => f.write_fmt(format_args!("{}: {e}", path.display())),
+            Error::Stdin(e) => f.write_fmt(format_args!("reading stdin: {e}")),
+            Error::NoPassword => f.write_fmt(format_args!(
+                "no password, set --password-file or the {PASSWORD_ENV} environment variable"
+            )),
+            Error::Decode(e) => f.write_fmt(format_args!("decoding private key: {e}")),
+            Error::Json(e) => f.write_fmt(format_args!("{e}")),
+            Error::Keystore(e) => f.write_fmt(format_args!("{e}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Cmd {
+    /// Encrypt a private key read from stdin into a keystore
+    Encrypt(EncryptCmd),
+    /// Decrypt a keystore and print the private key
+    Decrypt(DecryptCmd),
+    /// Print the address of a keystore without decrypting it
+    Show(ShowCmd),
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct PasswordArgs {
+    /// File to read the password from, otherwise the password is read from the
+    /// STELLAR_STRKEY_PASSWORD environment variable
+    #[arg(long)]
+    password_file: Option<PathBuf>,
+}
+
+impl PasswordArgs {
+    pub(crate) fn password(&self) -> Result<String, Error> {
+        match &self.password_file {
+            Some(path) => {
+                let s = fs::read_to_string(path).map_err(|e| Error::Io(path.clone(), e))?;
+                Ok(s.trim_end_matches(['\r', '\n']).to_string())
+            }
+            None => std::env::var(PASSWORD_ENV).map_err(|_| Error::NoPassword),
+        }
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct EncryptCmd {
+    #[command(flatten)]
+    password: PasswordArgs,
+    /// Scrypt cost parameter, as the base 2 logarithm of N
+    #[arg(long, default_value_t = ScryptParams::DEFAULT_LOG_N)]
+    log_n: u8,
+    /// File to write the keystore to, otherwise it is written to stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct DecryptCmd {
+    #[command(flatten)]
+    password: PasswordArgs,
+    /// Keystore file
+    #[arg()]
+    file: PathBuf,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct ShowCmd {
+    /// Keystore file
+    #[arg()]
+    file: PathBuf,
+}
+
+fn read_keystore(path: &PathBuf) -> Result<Keystore, Error> {
+    let s = fs::read_to_string(path).map_err(|e| Error::Io(path.clone(), e))?;
+    serde_json::from_str(&s).map_err(Error::Json)
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        match self {
+            Cmd::Encrypt(c) => c.run(),
+            Cmd::Decrypt(c) => c.run(),
+            Cmd::Show(c) => c.run(),
+        }
+    }
+}
+
+impl EncryptCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let mut secret = String::new();
+        io::stdin().read_line(&mut secret).map_err(Error::Stdin)?;
+        let key = ed25519::PrivateKey::from_string(secret.trim()).map_err(Error::Decode)?;
+        let password = self.password.password()?;
+        let keystore = Keystore::encrypt_with_cost(
+            &key,
+            password.as_bytes(),
+            self.log_n,
+            ScryptParams::DEFAULT_R,
+            ScryptParams::DEFAULT_P,
+        )
+        .map_err(Error::Keystore)?;
+        let json = serde_json::to_string_pretty(&keystore).map_err(Error::Json)?;
+        match &self.out {
+            Some(path) => fs::write(path, json + "\n").map_err(|e| Error::Io(path.clone(), e)),
+            None => {
+                println!("{json}");
+                Ok(())
+            }
+        }
+    }
+}
+
+impl DecryptCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let keystore = read_keystore(&self.file)?;
+        let password = self.password.password()?;
+        let key = keystore
+            .decrypt(password.as_bytes())
+            .map_err(Error::Keystore)?;
+        println!("{key}");
+        Ok(())
+    }
+}
+
+impl ShowCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let keystore = read_keystore(&self.file)?;
+        println!("{}", keystore.address);
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use clap::{Args, ValueEnum};
+
+use crate::{cli::keys, ContractDataDurability, ContractDataKey, DecodeError, LedgerKey, Strkey};
+
+#[derive(Debug)]
+pub enum Error {
+    Decode(String, DecodeError),
+    Keys(keys::Error),
+    DataKey(DecodeError),
+    InstanceDurability,
+    NotContractDurability(String),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Keys(inner) => f.write_fmt(format_args!("{inner}")),
+            Error::DataKey(inner) => f.write_fmt(format_args!("decoding data key: {inner}")),
+            Error::InstanceDurability => {
+                f.write_str("contract instance entries are always persistent")
+            }
+            Error::NotContractDurability(s) => f.write_fmt(format_args!(
+                "durability only applies to contract data, {s:?} is not a contract"
+            )),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// G, B, L or C strkeys, or @name of a stored identity
+    #[arg(required = true)]
+    strkeys: Vec<String>,
+    /// Symbol the contract data is stored under, otherwise the key of C strkeys is the contract instance
+    #[arg(long, conflicts_with = "key_xdr")]
+    symbol: Option<String>,
+    /// Base64 XDR SCVal the contract data is stored under, otherwise the key of C strkeys is the contract instance
+    #[arg(long)]
+    key_xdr: Option<String>,
+    /// Durability of the contract data, only valid when every strkey is a contract
+    #[arg(long, value_enum)]
+    durability: Option<Durability>,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Durability {
+    Temporary,
+    Persistent,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let key = match (&self.symbol, &self.key_xdr) {
+            (Some(symbol), _) => ContractDataKey::symbol(symbol).map_err(Error::DataKey)?,
+            (_, Some(xdr)) => ContractDataKey::from_xdr_base64(xdr).map_err(Error::DataKey)?,
+            (None, None) => ContractDataKey::Instance,
+        };
+        let durability = match (&self.durability, &key) {
+            (Some(Durability::Temporary), ContractDataKey::Instance) => {
+                return Err(Error::InstanceDurability)
+            }
+            (Some(Durability::Temporary), _) => ContractDataDurability::Temporary,
+            (Some(Durability::Persistent) | None, _) => ContractDataDurability::Persistent,
+        };
+        // Every strkey is checked before any ledger key is printed.
+        let ledger_keys = self
+            .strkeys
+            .iter()
+            .map(|s| self.ledger_key(s, &key, durability))
+            .collect::<Result<Vec<_>, _>>()?;
+        for ledger_key in ledger_keys {
+            println!("{}", ledger_key.to_xdr_base64());
+        }
+        Ok(())
+    }
+
+    fn ledger_key(
+        &self,
+        s: &str,
+        key: &ContractDataKey,
+        durability: ContractDataDurability,
+    ) -> Result<LedgerKey, Error> {
+        let resolved = keys::resolve(s).map_err(Error::Keys)?;
+        let strkey = Strkey::from_string(&resolved).map_err(|e| Error::Decode(s.to_string(), e))?;
+        let ledger_key = match strkey {
+            Strkey::PublicKeyEd25519(account) => LedgerKey::from(account),
+            Strkey::ClaimableBalance(balance) => LedgerKey::from(balance),
+            Strkey::LiquidityPool(pool) => LedgerKey::from(pool),
+            Strkey::Contract(contract) => LedgerKey::ContractData {
+                contract,
+                key: key.clone(),
+                durability,
+            },
+            _ => return Err(Error::Decode(s.to_string(), DecodeError::UnexpectedKind)),
+        };
+        if self.durability.is_some() && !matches!(ledger_key, LedgerKey::ContractData { .. }) {
+            return Err(Error::NotContractDurability(s.to_string()));
+        }
+        Ok(ledger_key)
+    }
+}
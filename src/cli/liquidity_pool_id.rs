@@ -0,0 +1,75 @@
+use clap::{Args, ValueEnum};
+
+use crate::{cli::keys, Asset, DecodeError, Decoded, LiquidityPool, LiquidityPoolIdError, Strkey};
+
+#[derive(Debug)]
+pub enum Error {
+    Asset(String, DecodeError),
+    Keys(keys::Error),
+    LiquidityPoolId(LiquidityPoolIdError),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Asset(s, inner) => f.write_fmt(format_args!("decoding asset {s:?}: {inner}")),
+            Error::Keys(inner) => f.write_fmt(format_args!("{inner}")),
+            Error::LiquidityPoolId(inner) => f.write_fmt(format_args!("{inner}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// First asset of the pair, as `native` or `CODE:ISSUER`, where the issuer may be @name of a stored identity
+    #[arg()]
+    asset_a: String,
+    /// Second asset of the pair, as `native` or `CODE:ISSUER`, where the issuer may be @name of a stored identity, must sort after the first asset
+    #[arg()]
+    asset_b: String,
+    /// Pool fee in basis points
+    #[arg(long, default_value_t = LiquidityPool::FEE_V18)]
+    fee: i32,
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    output: Output,
+}
+
+#[derive(Clone, Debug, Default, ValueEnum)]
+pub enum Output {
+    #[default]
+    Strkey,
+    Json,
+}
+
+/// Parses an asset, resolving an `@name` issuer to the stored identity.
+fn parse_asset(s: &str) -> Result<Asset, Error> {
+    let resolved = match s.split_once(':') {
+        Some((code, issuer)) => format!("{code}:{}", keys::resolve(issuer).map_err(Error::Keys)?),
+        None => s.to_string(),
+    };
+    resolved.parse().map_err(|e| Error::Asset(s.to_string(), e))
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let asset_a = parse_asset(&self.asset_a)?;
+        let asset_b = parse_asset(&self.asset_b)?;
+        let pool = LiquidityPool::from_constant_product(&asset_a, &asset_b, self.fee)
+            .map_err(Error::LiquidityPoolId)?;
+        let strkey = Strkey::LiquidityPool(pool);
+        match self.output {
+            Output::Strkey => println!("{strkey}"),
+            Output::Json => {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&Decoded(&strkey)).unwrap()
+                )
+            }
+        }
+        Ok(())
+    }
+}
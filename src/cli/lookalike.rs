@@ -0,0 +1,124 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+
+use crate::{
+    cli::{
+        address_book::{self, LabelArgs},
+        keys,
+    },
+    DecodeError, Similarity, Strkey,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Keys(keys::Error),
+    Decode(String, DecodeError),
+    Read(PathBuf, io::Error),
+    Known(PathBuf, usize, DecodeError),
+    LookalikeFound,
+    AddressBook(address_book::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Keys(e) => f.write_fmt(format_args!("{e}")),
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Read(path, e) => f.write_fmt(format_args!("reading {}: {e}", path.display())),
+            Error::Known(path, line, inner) => f.write_fmt(format_args!(
+                "{}: line {line}: {inner}",
+                path.display()
+            )),
+            Error::LookalikeFound => f.write_str(
+                "the address looks like a known address without being it, and may be an address poisoning attempt",
+            ),
+            Error::AddressBook(inner) => f.write_fmt(format_args!("{inner}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// Strkey to check, or @name of a stored identity
+    #[arg()]
+    address: String,
+    /// File of known strkeys, one per line, where blank lines and lines starting with # are ignored
+    #[arg(long, required_unless_present = "address_book")]
+    known: Option<PathBuf>,
+    /// Number of leading and of trailing characters that must match for an address to look like a known address
+    #[arg(
+        long,
+        default_value_t = Similarity::DEFAULT_MIN_MATCH,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+    )]
+    min_match: usize,
+    /// The addresses of the address book are known addresses too
+    #[command(flatten)]
+    labels: LabelArgs,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let address = keys::resolve(&self.address).map_err(Error::Keys)?;
+        let strkey =
+            Strkey::from_string(&address).map_err(|e| Error::Decode(address.clone(), e))?;
+        let book = self
+            .labels
+            .load()
+            .map_err(Error::AddressBook)?
+            .unwrap_or_default();
+        let mut known = match &self.known {
+            Some(path) => read_known(path)?,
+            None => Vec::new(),
+        };
+        known.extend(book.entries().map(|(_, strkey)| strkey.clone()));
+        known.sort();
+        known.dedup();
+        if known.contains(&strkey) {
+            match book.label(&strkey) {
+                Some(label) => println!("known\t{label}"),
+                None => println!("known"),
+            }
+            return Ok(());
+        }
+        let mut lookalikes = known
+            .iter()
+            .filter_map(|k| Some((k, strkey.lookalike(k, self.min_match)?)))
+            .collect::<Vec<_>>();
+        if lookalikes.is_empty() {
+            println!("no lookalikes");
+            return Ok(());
+        }
+        lookalikes.sort_by_key(|(_, similarity)| core::cmp::Reverse(similarity.score()));
+        for (known, similarity) in &lookalikes {
+            println!(
+                "{}\tscore {}, {} leading and {} trailing characters match",
+                book.annotate(known),
+                similarity.score(),
+                similarity.head,
+                similarity.tail
+            );
+        }
+        Err(Error::LookalikeFound)
+    }
+}
+
+fn read_known(path: &Path) -> Result<Vec<Strkey>, Error> {
+    let contents = fs::read_to_string(path).map_err(|e| Error::Read(path.to_path_buf(), e))?;
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(n, line)| {
+            Strkey::from_string(line).map_err(|e| Error::Known(path.to_path_buf(), n, e))
+        })
+        .collect()
+}
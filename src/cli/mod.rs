@@ -1,6 +1,23 @@
+pub mod address_book;
 pub mod decode;
+pub mod demux;
 pub mod encode;
+pub mod export;
+pub mod import;
+pub mod keys;
+pub mod keystore;
+pub mod ledger_key;
+pub mod liquidity_pool_id;
+pub mod lookalike;
+pub mod mux;
+pub mod open;
+pub mod seal;
+pub mod shares;
+pub mod sign_message;
+pub mod signed_payload;
+pub mod verify_message;
 pub mod version;
+pub mod well_known;
 pub mod zero;
 
 use clap::{Parser, Subcommand};
@@ -30,6 +47,43 @@ enum Cmd {
     Encode(encode::Cmd),
     /// Generate the zero strkey
     Zero(zero::Cmd),
+    /// Derive the liquidity pool strkey for an asset pair
+    LiquidityPoolId(liquidity_pool_id::Cmd),
+    /// Encrypt and decrypt private keys in keystore files
+    #[command(subcommand)]
+    Keystore(keystore::Cmd),
+    /// Manage named identities that other commands accept as @name
+    #[command(subcommand)]
+    Keys(keys::Cmd),
+    /// Encrypt stdin so only the holder of a G strkey's private key can decrypt it
+    Seal(seal::Cmd),
+    /// Decrypt stdin sealed to a stored identity's G strkey
+    Open(open::Cmd),
+    /// Sign a message with a stored identity, as specified by SEP-53
+    SignMessage(sign_message::Cmd),
+    /// Verify a SEP-53 message signature by a G strkey
+    VerifyMessage(verify_message::Cmd),
+    /// Build and check CAP-40 signed payload signers
+    #[command(subcommand)]
+    SignedPayload(signed_payload::Cmd),
+    /// Split private keys into shares, and recombine them
+    #[command(subcommand)]
+    Shares(shares::Cmd),
+    /// Import a key from PKCS#8, SubjectPublicKeyInfo, OpenSSH, libsodium, JWK, multibase or did:key format
+    Import(import::Cmd),
+    /// Export a key to PKCS#8, SubjectPublicKeyInfo, OpenSSH, libsodium, JWK, multibase or did:key format
+    Export(export::Cmd),
+    /// Multiplex a G strkey and memo ID into an M strkey, as specified by SEP-23
+    Mux(mux::Cmd),
+    /// Split M strkeys into their G strkey and memo ID
+    Demux(demux::Cmd),
+    /// Derive the base64 XDR ledger keys of G, B, L and C strkeys, as RPC getLedgerEntries expects
+    LedgerKey(ledger_key::Cmd),
+    /// Check whether an address looks like a known address without being it, as in address poisoning
+    Lookalike(lookalike::Cmd),
+    /// Manage an address book of labeled addresses, which other commands print labels from with --address-book
+    #[command(subcommand)]
+    AddressBook(address_book::Cmd),
     /// Print version information
     Version,
 }
@@ -45,6 +99,22 @@ impl Root {
             Cmd::Decode(c) => c.run()?,
             Cmd::Encode(c) => c.run()?,
             Cmd::Zero(c) => c.run(),
+            Cmd::LiquidityPoolId(c) => c.run()?,
+            Cmd::Keystore(c) => c.run()?,
+            Cmd::Keys(c) => c.run()?,
+            Cmd::Seal(c) => c.run()?,
+            Cmd::Open(c) => c.run()?,
+            Cmd::SignMessage(c) => c.run()?,
+            Cmd::VerifyMessage(c) => c.run()?,
+            Cmd::SignedPayload(c) => c.run()?,
+            Cmd::Shares(c) => c.run()?,
+            Cmd::Import(c) => c.run()?,
+            Cmd::Export(c) => c.run()?,
+            Cmd::Mux(c) => c.run()?,
+            Cmd::Demux(c) => c.run()?,
+            Cmd::LedgerKey(c) => c.run()?,
+            Cmd::Lookalike(c) => c.run()?,
+            Cmd::AddressBook(c) => c.run()?,
             Cmd::Version => version::Cmd::run(),
         }
         Ok(())
@@ -59,6 +129,38 @@ pub enum Error {
     Decode(#[from] decode::Error),
     #[error(transparent)]
     Encode(#[from] encode::Error),
+    #[error(transparent)]
+    LiquidityPoolId(#[from] liquidity_pool_id::Error),
+    #[error(transparent)]
+    Keystore(#[from] keystore::Error),
+    #[error(transparent)]
+    Keys(#[from] keys::Error),
+    #[error(transparent)]
+    Seal(#[from] seal::Error),
+    #[error(transparent)]
+    Open(#[from] open::Error),
+    #[error(transparent)]
+    SignMessage(#[from] sign_message::Error),
+    #[error(transparent)]
+    VerifyMessage(#[from] verify_message::Error),
+    #[error(transparent)]
+    SignedPayload(#[from] signed_payload::Error),
+    #[error(transparent)]
+    Shares(#[from] shares::Error),
+    #[error(transparent)]
+    Import(#[from] import::Error),
+    #[error(transparent)]
+    Export(#[from] export::Error),
+    #[error(transparent)]
+    Mux(#[from] mux::Error),
+    #[error(transparent)]
+    Demux(#[from] demux::Error),
+    #[error(transparent)]
+    LedgerKey(#[from] ledger_key::Error),
+    #[error(transparent)]
+    Lookalike(#[from] lookalike::Error),
+    #[error(transparent)]
+    AddressBook(#[from] address_book::Error),
 }
 
 /// Run the CLI with the given args.
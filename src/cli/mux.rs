@@ -0,0 +1,283 @@
+use std::io::{self, BufRead};
+
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+
+use crate::{
+    cli::{
+        address_book::{self, AddressBook, LabelArgs},
+        keys,
+        well_known::WellKnownArgs,
+    },
+    ed25519, DecodeError, Strkey,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Keys(keys::Error),
+    Decode(String, DecodeError),
+    Stdin(io::Error),
+    Csv(usize, String),
+    Json(usize, serde_json::Error),
+    IdOverflow(u64, u64),
+    AddressBook(address_book::Error),
+    Line(usize, Box<Error>),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Keys(inner) => f.write_fmt(format_args!("{inner}")),
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Stdin(e) => f.write_fmt(format_args!("reading stdin: {e}")),
+            Error::Csv(line, msg) => f.write_fmt(format_args!("line {line}: {msg}")),
+            Error::Json(line, e) => f.write_fmt(format_args!("line {line}: {e}")),
+            Error::IdOverflow(id, count) => f.write_fmt(format_args!(
+                "{count} IDs starting at {id} exceed the largest ID {}",
+                u64::MAX
+            )),
+            Error::AddressBook(inner) => f.write_fmt(format_args!("{inner}")),
+            Error::Line(line, inner) => f.write_fmt(format_args!("line {line}: {inner}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// G strkey or @name of a stored identity, otherwise (account, ID) pairs are read from stdin
+    #[arg(requires = "id")]
+    account: Option<String>,
+    /// Memo ID to multiplex, or the first ID of the range with --count
+    #[arg()]
+    id: Option<u64>,
+    /// Number of consecutive IDs to generate muxed accounts for, starting at each ID
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    count: u64,
+    #[command(flatten)]
+    input: InputArgs,
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    output: Output,
+    #[command(flatten)]
+    labels: LabelArgs,
+    #[command(flatten)]
+    well_known: WellKnownArgs,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct InputArgs {
+    /// Format of the rows read from stdin
+    #[arg(long, value_enum, default_value_t)]
+    pub(crate) input: Input,
+    /// Skip the first row of CSV read from stdin
+    #[arg(long)]
+    header: bool,
+}
+
+#[derive(Clone, Debug, Default, ValueEnum)]
+pub enum Input {
+    /// Comma separated rows, `G…,ID` for mux and `M…` for demux
+    #[default]
+    Csv,
+    /// JSON objects, one per line, `{"account":"G…","id":ID}` for mux and `{"muxed_account":"M…"}` for demux
+    Json,
+}
+
+#[derive(Clone, Debug, Default, ValueEnum)]
+pub enum Output {
+    /// The M strkey, one per line, followed by its label if labeled
+    #[default]
+    Strkey,
+    /// Comma separated rows of account, ID and muxed account, and with
+    /// --address-book or --well-known their labels
+    Csv,
+    /// JSON objects of account, ID as a string and muxed account, and the
+    /// labels of those labeled, one per line
+    Json,
+}
+
+/// A muxed account with the account and ID it multiplexes, as output by the
+/// mux and demux commands.
+#[serde_as]
+#[derive(Serialize)]
+pub(crate) struct Record {
+    account: ed25519::PublicKey,
+    // IDs are written as strings, since JSON numbers above 2^53 are not safe
+    // in many languages.
+    #[serde_as(as = "DisplayFromStr")]
+    id: u64,
+    muxed_account: ed25519::MuxedAccount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    muxed_account_label: Option<String>,
+}
+
+impl From<ed25519::MuxedAccount> for Record {
+    fn from(muxed: ed25519::MuxedAccount) -> Self {
+        Record {
+            account: muxed.public_key(),
+            id: muxed.id,
+            muxed_account: muxed,
+            account_label: None,
+            muxed_account_label: None,
+        }
+    }
+}
+
+impl Record {
+    /// Sets the labels of the account and muxed account from the address
+    /// book if there is one, or to what they are if well-known and requested.
+    pub(crate) fn labeled(
+        mut self,
+        book: Option<&AddressBook>,
+        well_known: &WellKnownArgs,
+    ) -> Self {
+        let label = |strkey: Strkey| well_known.annotation(book, &strkey);
+        self.account_label = label(Strkey::PublicKeyEd25519(self.account));
+        self.muxed_account_label = label(Strkey::MuxedAccountEd25519(self.muxed_account));
+        self
+    }
+
+    /// Returns the record as a CSV row, with columns for the labels if
+    /// `labels` is set.
+    pub(crate) fn to_csv(&self, labels: bool) -> String {
+        let row = format!("{},{},{}", self.account, self.id, self.muxed_account);
+        if !labels {
+            return row;
+        }
+        let account_label = self.account_label.as_deref().unwrap_or_default();
+        let muxed_account_label = self.muxed_account_label.as_deref().unwrap_or_default();
+        format!("{row},{account_label},{muxed_account_label}")
+    }
+
+    pub(crate) fn muxed_account(&self) -> String {
+        match &self.muxed_account_label {
+            Some(label) => format!("{}\t{label}", self.muxed_account),
+            None => format!("{}", self.muxed_account),
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+struct JsonInput {
+    account: String,
+    // IDs are accepted as strings too, since JSON numbers above 2^53 are not
+    // safe in many languages.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    id: u64,
+}
+
+impl InputArgs {
+    /// Reads stdin, returning the non-empty rows with their line numbers.
+    pub(crate) fn rows(&self) -> Result<Vec<(usize, String)>, Error> {
+        let lines = io::stdin()
+            .lock()
+            .lines()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::Stdin)?;
+        let skip = usize::from(self.header && matches!(self.input, Input::Csv));
+        Ok(lines
+            .into_iter()
+            .enumerate()
+            .skip(skip)
+            .map(|(i, line)| (i + 1, line.trim().to_string()))
+            .filter(|(_, line)| !line.is_empty())
+            .collect())
+    }
+}
+
+/// Splits a CSV row into fields, removing whitespace and quotes around each.
+pub(crate) fn csv_fields(row: &str) -> Vec<&str> {
+    row.split(',')
+        .map(|field| field.trim().trim_matches('"').trim())
+        .collect()
+}
+
+/// Parses a G strkey, resolving `@name` to the stored identity.
+fn parse_account(s: &str) -> Result<ed25519::PublicKey, Error> {
+    let resolved = keys::resolve(s).map_err(Error::Keys)?;
+    match Strkey::from_string(&resolved) {
+        Ok(Strkey::PublicKeyEd25519(key)) => Ok(key),
+        Ok(_) => Err(Error::Decode(s.to_string(), DecodeError::UnexpectedKind)),
+        Err(e) => Err(Error::Decode(s.to_string(), e)),
+    }
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let pairs = match (&self.account, self.id) {
+            (Some(account), Some(id)) => vec![(parse_account(account)?, id)],
+            _ => self.read_pairs()?,
+        };
+        let book = self.labels.load().map_err(Error::AddressBook)?;
+        for (account, id) in pairs {
+            let last = id
+                .checked_add(self.count - 1)
+                .ok_or(Error::IdOverflow(id, self.count))?;
+            for id in id..=last {
+                let record =
+                    Record::from(account.muxed(id)).labeled(book.as_ref(), &self.well_known);
+                match self.output {
+                    Output::Strkey => println!("{}", record.muxed_account()),
+                    Output::Csv => println!(
+                        "{}",
+                        record.to_csv(book.is_some() || self.well_known.enabled())
+                    ),
+                    Output::Json => println!("{}", record.to_json()),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_pairs(&self) -> Result<Vec<(ed25519::PublicKey, u64)>, Error> {
+        self.input
+            .rows()?
+            .into_iter()
+            .map(|(line, row)| match self.input.input {
+                Input::Csv => {
+                    let fields = csv_fields(&row);
+                    let [account, id, ..] = fields.as_slice() else {
+                        return Err(Error::Csv(line, "expected account,id".to_string()));
+                    };
+                    let id = id
+                        .parse()
+                        .map_err(|_| Error::Csv(line, format!("invalid id {id:?}")))?;
+                    let account =
+                        parse_account(account).map_err(|e| Error::Line(line, Box::new(e)))?;
+                    Ok((account, id))
+                }
+                Input::Json => {
+                    let input: JsonInput =
+                        serde_json::from_str(&row).map_err(|e| Error::Json(line, e))?;
+                    let account = parse_account(&input.account)
+                        .map_err(|e| Error::Line(line, Box::new(e)))?;
+                    Ok((account, input.id))
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Record;
+    use crate::ed25519;
+
+    #[test]
+    fn test_record_json_id() {
+        let muxed = ed25519::PublicKey([0; 32]).muxed(u64::MAX);
+        let json: serde_json::Value = serde_json::from_str(&Record::from(muxed).to_json()).unwrap();
+        assert_eq!(json["id"], "18446744073709551615");
+    }
+}
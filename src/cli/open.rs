@@ -0,0 +1,56 @@
+use std::io::{self, Read, Write};
+
+use clap::Args;
+
+use crate::{
+    cli::{keys, keystore::PasswordArgs, seal::Format},
+    seal,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Keys(keys::Error),
+    Stdin(io::Error),
+    Stdout(io::Error),
+    Input,
+    Seal(seal::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Keys(e) => f.write_fmt(format_args!("{e}")),
+            Error::Stdin(e) => f.write_fmt(format_args!("reading stdin: {e}")),
+            Error::Stdout(e) => f.write_fmt(format_args!("writing stdout: {e}")),
+            Error::Input => f.write_str("ciphertext is not in the input format"),
+            Error::Seal(e) => f.write_fmt(format_args!("{e}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// Name of the stored identity holding the private key
+    #[arg()]
+    identity: String,
+    /// Input format of the ciphertext
+    #[arg(long, value_enum, default_value_t)]
+    input: Format,
+    #[command(flatten)]
+    password: PasswordArgs,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let name = self.identity.strip_prefix('@').unwrap_or(&self.identity);
+        let key = keys::load_secret(name, &self.password).map_err(Error::Keys)?;
+        let mut input = Vec::new();
+        io::stdin().read_to_end(&mut input).map_err(Error::Stdin)?;
+        let ciphertext = self.input.decode(&input).ok_or(Error::Input)?;
+        let message = seal::open(&key, &ciphertext).map_err(Error::Seal)?;
+        io::stdout().write_all(&message).map_err(Error::Stdout)
+    }
+}
@@ -0,0 +1,82 @@
+use std::io::{self, Read, Write};
+
+use clap::{Args, ValueEnum};
+
+use crate::{cli::keys, ed25519, seal, DecodeError};
+
+#[derive(Debug)]
+pub enum Error {
+    Keys(keys::Error),
+    Decode(String, DecodeError),
+    Stdin(io::Error),
+    Stdout(io::Error),
+    Seal(seal::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Keys(e) => f.write_fmt(format_args!("{e}")),
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Stdin(e) => f.write_fmt(format_args!("reading stdin: {e}")),
+            Error::Stdout(e) => f.write_fmt(format_args!("writing stdout: {e}")),
+            Error::Seal(e) => f.write_fmt(format_args!("{e}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Encoding of ciphertexts on stdin and stdout.
+#[derive(Clone, Debug, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Base64,
+    Hex,
+    Raw,
+}
+
+impl Format {
+    pub(crate) fn encode(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Format::Base64 => (data_encoding::BASE64.encode(data) + "\n").into_bytes(),
+            Format::Hex => (data_encoding::HEXLOWER.encode(data) + "\n").into_bytes(),
+            Format::Raw => data.to_vec(),
+        }
+    }
+
+    pub(crate) fn decode(&self, data: &[u8]) -> Option<Vec<u8>> {
+        let trimmed = data.trim_ascii();
+        match self {
+            Format::Base64 => data_encoding::BASE64.decode(trimmed).ok(),
+            Format::Hex => data_encoding::HEXLOWER_PERMISSIVE.decode(trimmed).ok(),
+            Format::Raw => Some(data.to_vec()),
+        }
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// G strkey of the recipient, or @name of a stored identity
+    #[arg()]
+    to: String,
+    /// Output format of the ciphertext
+    #[arg(long, value_enum, default_value_t)]
+    output: Format,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let to = keys::resolve(&self.to).map_err(Error::Keys)?;
+        let to = ed25519::PublicKey::from_string(&to).map_err(|e| Error::Decode(to, e))?;
+        let mut message = Vec::new();
+        io::stdin()
+            .read_to_end(&mut message)
+            .map_err(Error::Stdin)?;
+        let ciphertext = seal::seal(&to, &message).map_err(Error::Seal)?;
+        io::stdout()
+            .write_all(&self.output.encode(&ciphertext))
+            .map_err(Error::Stdout)
+    }
+}
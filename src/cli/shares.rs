@@ -0,0 +1,101 @@
+use std::io::{self, BufRead};
+
+use clap::{Args, Subcommand};
+
+use crate::{
+    ed25519,
+    shares::{self, Share},
+    DecodeError,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Stdin(io::Error),
+    Decode(DecodeError),
+    Share(String, DecodeError),
+    Shares(shares::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Stdin(e) => f.write_fmt(format_args!("reading stdin: {e}")),
+            Error::Decode(e) => f.write_fmt(format_args!("decoding private key: {e}")),
+            Error::Share(s, inner) => f.write_fmt(format_args!("decoding share {s:?}: {inner}")),
+            Error::Shares(e) => f.write_fmt(format_args!("{e}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Cmd {
+    /// Split a private key read from stdin into shares, one per line
+    Split(SplitCmd),
+    /// Recombine shares into the private key
+    Combine(CombineCmd),
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct SplitCmd {
+    /// Number of shares needed to recombine the private key
+    #[arg(long, short = 'k')]
+    threshold: u8,
+    /// Number of shares to split the private key into
+    #[arg(long, short = 'n')]
+    shares: u8,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct CombineCmd {
+    /// Shares to recombine, otherwise shares are read from stdin, one per line
+    #[arg()]
+    shares: Vec<String>,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        match self {
+            Cmd::Split(c) => c.run(),
+            Cmd::Combine(c) => c.run(),
+        }
+    }
+}
+
+impl SplitCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let mut secret = String::new();
+        io::stdin().read_line(&mut secret).map_err(Error::Stdin)?;
+        let key = ed25519::PrivateKey::from_string(secret.trim()).map_err(Error::Decode)?;
+        for share in shares::split(&key, self.threshold, self.shares).map_err(Error::Shares)? {
+            println!("{share}");
+        }
+        Ok(())
+    }
+}
+
+impl CombineCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let lines = if self.shares.is_empty() {
+            io::stdin()
+                .lock()
+                .lines()
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Error::Stdin)?
+        } else {
+            self.shares.clone()
+        };
+        let shares = lines
+            .iter()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| Share::from_string(s).map_err(|e| Error::Share(s.to_string(), e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let key = shares::combine(&shares).map_err(Error::Shares)?;
+        println!("{key}");
+        Ok(())
+    }
+}
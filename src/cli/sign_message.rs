@@ -0,0 +1,75 @@
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::PathBuf,
+};
+
+use clap::Args;
+
+use crate::{
+    cli::{keys, keystore::PasswordArgs, seal::Format},
+    message,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Keys(keys::Error),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+    Stdout(io::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Keys(e) => f.write_fmt(format_args!("{e}")),
+            Error::Io(path, e) => f.write_fmt(format_args!("{}: {e}", path.display())),
+            Error::Stdin(e) => f.write_fmt(format_args!("reading stdin: {e}")),
+            Error::Stdout(e) => f.write_fmt(format_args!("writing stdout: {e}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// Name of the stored identity holding the private key
+    #[arg()]
+    identity: String,
+    /// File containing the message, otherwise the message is read from stdin
+    #[arg()]
+    file: Option<PathBuf>,
+    /// Output format of the signature
+    #[arg(long, value_enum, default_value_t)]
+    output: Format,
+    #[command(flatten)]
+    password: PasswordArgs,
+}
+
+/// Reads the message from the file, or from stdin if there is no file.
+pub(crate) fn read_message(file: Option<&PathBuf>) -> Result<Vec<u8>, Error> {
+    match file {
+        Some(path) => fs::read(path).map_err(|e| Error::Io(path.clone(), e)),
+        None => {
+            let mut message = Vec::new();
+            io::stdin()
+                .read_to_end(&mut message)
+                .map_err(Error::Stdin)?;
+            Ok(message)
+        }
+    }
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let name = self.identity.strip_prefix('@').unwrap_or(&self.identity);
+        let key = keys::load_secret(name, &self.password).map_err(Error::Keys)?;
+        let message = read_message(self.file.as_ref())?;
+        let signature = message::sign(&key, &message);
+        io::stdout()
+            .write_all(&self.output.encode(&signature))
+            .map_err(Error::Stdout)
+    }
+}
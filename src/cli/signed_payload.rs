@@ -0,0 +1,120 @@
+use clap::{Args, Subcommand, ValueEnum};
+
+use crate::{cli::keys, cli::verify_message, ed25519, DecodeError, SignedPayloadError};
+
+#[derive(Debug)]
+pub enum Error {
+    Keys(keys::Error),
+    Decode(String, DecodeError),
+    Payload(String),
+    SignedPayload(SignedPayloadError),
+    Signature(String),
+    Unsatisfied,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Keys(e) => f.write_fmt(format_args!("{e}")),
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Payload(s) => f.write_fmt(format_args!("payload {s:?} is not hex")),
+            Error::SignedPayload(e) => f.write_fmt(format_args!("{e}")),
+            Error::Signature(s) => f.write_fmt(format_args!(
+                "{s:?} is not a 64 byte signature in base64 or hex"
+            )),
+            Error::Unsatisfied => {
+                f.write_str("signature is not a signature over the payload by the signer")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Cmd {
+    /// Build the P strkey of a signed payload signer from a G strkey and payload
+    Build(BuildCmd),
+    /// Check that a signature satisfies a signed payload signer
+    Check(CheckCmd),
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct BuildCmd {
+    /// G strkey of the signer, or @name of a stored identity
+    #[arg()]
+    address: String,
+    /// Payload in hex, 1 to 64 bytes
+    #[arg()]
+    payload: String,
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    output: Output,
+}
+
+#[derive(Clone, Debug, Default, ValueEnum)]
+pub enum Output {
+    /// The P strkey
+    #[default]
+    Strkey,
+    /// The P strkey and the hint of decorated signatures for it
+    Json,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct CheckCmd {
+    /// P strkey of the signer
+    #[arg()]
+    signer: String,
+    /// Signature over the payload in base64 or hex
+    #[arg()]
+    signature: String,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        match self {
+            Cmd::Build(c) => c.run(),
+            Cmd::Check(c) => c.run(),
+        }
+    }
+}
+
+impl BuildCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let address = keys::resolve(&self.address).map_err(Error::Keys)?;
+        let key = ed25519::PublicKey::from_string(&address)
+            .map_err(|e| Error::Decode(address.clone(), e))?;
+        let payload = data_encoding::HEXLOWER_PERMISSIVE
+            .decode(self.payload.as_bytes())
+            .map_err(|_| Error::Payload(self.payload.clone()))?;
+        let signer = ed25519::SignedPayload::new(key, &payload).map_err(Error::SignedPayload)?;
+        match self.output {
+            Output::Strkey => println!("{signer}"),
+            Output::Json => {
+                let json = serde_json::json!({
+                    "signer": signer.to_string().as_str(),
+                    "hint": data_encoding::HEXLOWER.encode(&signer.hint()),
+                });
+                println!("{}", serde_json::to_string_pretty(&json).unwrap());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CheckCmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let signer = ed25519::SignedPayload::from_string(&self.signer)
+            .map_err(|e| Error::Decode(self.signer.clone(), e))?;
+        let signature = verify_message::parse_signature(&self.signature)
+            .ok_or_else(|| Error::Signature(self.signature.clone()))?;
+        if !signer.is_satisfied_by(&signature) {
+            return Err(Error::Unsatisfied);
+        }
+        println!("valid");
+        Ok(())
+    }
+}
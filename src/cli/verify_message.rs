@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::{
+    cli::{keys, seal::Format, sign_message},
+    ed25519, message, DecodeError,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Keys(keys::Error),
+    Decode(String, DecodeError),
+    Signature(String),
+    Read(sign_message::Error),
+    Message(message::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Keys(e) => f.write_fmt(format_args!("{e}")),
+            Error::Decode(s, inner) => f.write_fmt(format_args!("decoding {s:?}: {inner}")),
+            Error::Signature(s) => f.write_fmt(format_args!(
+                "{s:?} is not a 64 byte signature in base64 or hex"
+            )),
+            Error::Read(e) => f.write_fmt(format_args!("{e}")),
+            Error::Message(e) => f.write_fmt(format_args!("{e}")),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Args, Debug, Clone)]
+#[command()]
+pub struct Cmd {
+    /// G strkey of the signer, or @name of a stored identity
+    #[arg()]
+    address: String,
+    /// Signature in base64 or hex
+    #[arg()]
+    signature: String,
+    /// File containing the message, otherwise the message is read from stdin
+    #[arg()]
+    file: Option<PathBuf>,
+}
+
+impl Cmd {
+    pub fn run(&self) -> Result<(), Error> {
+        let address = keys::resolve(&self.address).map_err(Error::Keys)?;
+        let key = ed25519::PublicKey::from_string(&address)
+            .map_err(|e| Error::Decode(address.clone(), e))?;
+        let signature = parse_signature(&self.signature)
+            .ok_or_else(|| Error::Signature(self.signature.clone()))?;
+        let message = sign_message::read_message(self.file.as_ref()).map_err(Error::Read)?;
+        message::verify(&key, &message, &signature).map_err(Error::Message)?;
+        println!("valid");
+        Ok(())
+    }
+}
+
+/// Parses a signature, as hex if it is 128 characters long and otherwise as
+/// base64.
+pub(crate) fn parse_signature(s: &str) -> Option<[u8; 64]> {
+    let format = if s.len() == 128 {
+        Format::Hex
+    } else {
+        Format::Base64
+    };
+    format.decode(s.as_bytes())?.try_into().ok()
+}
@@ -0,0 +1,58 @@
+use clap::Args;
+
+use crate::{cli::address_book::AddressBook, well_known::WellKnown, Strkey};
+
+/// Arguments of commands that print what well-known addresses are next to
+/// them.
+#[derive(Args, Debug, Clone)]
+pub struct WellKnownArgs {
+    /// Print what well-known addresses are next to them, such as the XLM
+    /// Stellar Asset Contract of each network and the all-zero addresses
+    #[arg(long)]
+    well_known: bool,
+}
+
+impl WellKnownArgs {
+    /// Returns true if well-known addresses are to be annotated.
+    pub(crate) fn enabled(&self) -> bool {
+        self.well_known
+    }
+
+    /// Returns what the strkey is if it is well-known and well-known
+    /// addresses are to be annotated.
+    pub(crate) fn lookup(&self, strkey: &Strkey) -> Option<&'static WellKnown> {
+        self.well_known.then(|| strkey.well_known()).flatten()
+    }
+
+    /// Returns the label of the strkey in the address book if there is one,
+    /// otherwise what it is if it is well-known and well-known addresses are
+    /// to be annotated.
+    pub(crate) fn annotation(&self, book: Option<&AddressBook>, strkey: &Strkey) -> Option<String> {
+        book.and_then(|book| book.label(strkey))
+            .map(str::to_string)
+            .or_else(|| self.lookup(strkey).map(|w| w.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WellKnownArgs;
+    use crate::{cli::address_book::AddressBook, well_known, Strkey};
+
+    #[test]
+    fn test_annotation() {
+        let xlm = Strkey::Contract(well_known::XLM_PUBNET);
+        let off = WellKnownArgs { well_known: false };
+        let on = WellKnownArgs { well_known: true };
+        assert_eq!(off.annotation(None, &xlm), None);
+        assert_eq!(
+            on.annotation(None, &xlm).as_deref(),
+            Some("XLM Stellar Asset Contract on pubnet")
+        );
+        // Labels take precedence over what well-known addresses are.
+        let mut book = AddressBook::default();
+        book.insert("XLM", xlm.clone()).unwrap();
+        assert_eq!(off.annotation(Some(&book), &xlm).as_deref(), Some("XLM"));
+        assert_eq!(on.annotation(Some(&book), &xlm).as_deref(), Some("XLM"));
+    }
+}
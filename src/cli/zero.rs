@@ -1,6 +1,4 @@
-use crate::{
-    ed25519, ClaimableBalance, Contract, Decoded, HashX, LiquidityPool, PreAuthTx, Strkey,
-};
+use crate::{well_known, Decoded, Strkey};
 use clap::{Args, ValueEnum};
 
 #[derive(Args, Debug, Clone)]
@@ -39,23 +37,19 @@ pub enum Output {
 impl Cmd {
     pub fn run(&self) {
         let strkey = match self.strkey {
-            StrkeyType::PublicKeyEd25519 => Strkey::PublicKeyEd25519(ed25519::PublicKey([0; 32])),
-            StrkeyType::PreAuthTx => Strkey::PreAuthTx(PreAuthTx([0; 32])),
-            StrkeyType::HashX => Strkey::HashX(HashX([0; 32])),
-            StrkeyType::MuxedAccountEd25519 => Strkey::MuxedAccountEd25519(ed25519::MuxedAccount {
-                ed25519: [0; 32],
-                id: 0,
-            }),
+            StrkeyType::PublicKeyEd25519 => Strkey::PublicKeyEd25519(well_known::ZERO_ACCOUNT),
+            StrkeyType::PreAuthTx => Strkey::PreAuthTx(well_known::ZERO_PRE_AUTH_TX),
+            StrkeyType::HashX => Strkey::HashX(well_known::ZERO_HASH_X),
+            StrkeyType::MuxedAccountEd25519 => {
+                Strkey::MuxedAccountEd25519(well_known::ZERO_MUXED_ACCOUNT)
+            }
             StrkeyType::SignedPayloadEd25519 => {
-                Strkey::SignedPayloadEd25519(ed25519::SignedPayload {
-                    ed25519: [0; 32],
-                    payload: Default::default(),
-                })
+                Strkey::SignedPayloadEd25519(well_known::ZERO_SIGNED_PAYLOAD)
             }
-            StrkeyType::Contract => Strkey::Contract(Contract([0; 32])),
-            StrkeyType::LiquidityPool => Strkey::LiquidityPool(LiquidityPool([0; 32])),
+            StrkeyType::Contract => Strkey::Contract(well_known::ZERO_CONTRACT),
+            StrkeyType::LiquidityPool => Strkey::LiquidityPool(well_known::ZERO_LIQUIDITY_POOL),
             StrkeyType::ClaimableBalanceV0 => {
-                Strkey::ClaimableBalance(ClaimableBalance::V0([0; 32]))
+                Strkey::ClaimableBalance(well_known::ZERO_CLAIMABLE_BALANCE)
             }
         };
         match self.output {
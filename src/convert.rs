@@ -16,7 +16,7 @@ pub const fn binary_len(payload_len: usize) -> usize {
 ///
 /// The formula is `ceil(binary_len * 8 / 5)`.
 pub const fn encode_len(binary_len: usize) -> usize {
-    (binary_len * 8 + 4) / 5
+    (binary_len * 8).div_ceil(5)
 }
 
 // Buffer sizes expected per strkey version:
@@ -53,22 +53,54 @@ pub fn encode<const P: usize, const B: usize, const E: usize>(
     ver: u8,
     payload: &[u8],
 ) -> String<E> {
+    encode_base32::<B, E>(&encode_binary::<P, B>(ver, payload))
+}
+
+/// Encodes a version byte and payload into a binary strkey, which is the
+/// strkey before it is base32 encoded.
+///
+/// The binary format is: `version (1 byte) || payload || checksum (2 bytes)`.
+/// The checksum is computed over the version and payload bytes.
+///
+/// # Generic Parameters
+///
+/// - `P` - Payload buffer capacity
+/// - `B` - Binary buffer capacity (must be exactly binary_len(P))
+///
+/// # Panics
+///
+/// Panics if the binary data exceeds `B` bytes.
+pub fn encode_binary<const P: usize, const B: usize>(ver: u8, payload: &[u8]) -> Vec<u8, B> {
     const {
         assert!(B == binary_len(P), "B must be exactly binary_len(P)");
-        assert!(E == encode_len(B), "E must be exactly encode_len(B)");
     }
 
-    // Build binary.
     let mut d: Vec<u8, B> = Vec::new();
     d.push(ver).unwrap();
     d.extend_from_slice(payload).unwrap();
     d.extend_from_slice(&checksum(&d)).unwrap();
+    d
+}
+
+/// Encodes a binary strkey as a base32 strkey string.
+///
+/// # Generic Parameters
+///
+/// - `B` - Binary buffer capacity
+/// - `E` - Encoded output capacity (must be exactly encode_len(B))
+///
+/// # Panics
+///
+/// Panics if the encoded output exceeds `E` bytes.
+pub fn encode_base32<const B: usize, const E: usize>(binary: &[u8]) -> String<E> {
+    const {
+        assert!(E == encode_len(B), "E must be exactly encode_len(B)");
+    }
 
-    // Encode as base32.
     let mut encoded: Vec<u8, E> = Vec::new();
-    let encoded_len = data_encoding::BASE32_NOPAD.encode_len(d.len());
+    let encoded_len = data_encoding::BASE32_NOPAD.encode_len(binary.len());
     encoded.resize_default(encoded_len).unwrap();
-    data_encoding::BASE32_NOPAD.encode_mut(&d, &mut encoded);
+    data_encoding::BASE32_NOPAD.encode_mut(binary, &mut encoded);
 
     // SAFETY: base32 encoding produces valid ASCII which is valid UTF-8
     unsafe { String::from_utf8_unchecked(encoded) }
@@ -89,10 +121,6 @@ pub fn encode<const P: usize, const B: usize, const E: usize>(
 /// - The input is not valid base32
 /// - The decoded data is less than 3 bytes, meaning there is no payload
 /// - The checksum does not match
-///
-/// # Panics
-///
-/// Panics if the binary data exceeds `B` bytes.
 pub fn decode<const P: usize, const B: usize>(s: &[u8]) -> Result<(u8, Vec<u8, P>), DecodeError> {
     const {
         assert!(B == binary_len(P), "B must be exactly binary_len(P)");
@@ -103,9 +131,6 @@ pub fn decode<const P: usize, const B: usize>(s: &[u8]) -> Result<(u8, Vec<u8, P
     let data_len = data_encoding::BASE32_NOPAD
         .decode_len(s.len())
         .map_err(|_| DecodeError::Invalid)?;
-    if data_len < 3 {
-        return Err(DecodeError::Invalid);
-    }
     data.resize_default(data_len)
         .map_err(|_| DecodeError::Invalid)?;
 
@@ -114,6 +139,35 @@ pub fn decode<const P: usize, const B: usize>(s: &[u8]) -> Result<(u8, Vec<u8, P
         .decode_mut(s, &mut data)
         .map_err(|_| DecodeError::Invalid)?;
 
+    decode_binary::<P, B>(&data)
+}
+
+/// Decodes a binary strkey into a version byte and payload.
+///
+/// The binary format is: `version (1 byte) || payload || checksum (2 bytes)`.
+///
+/// # Generic Parameters
+///
+/// - `P` - Payload buffer capacity
+/// - `B` - Binary buffer capacity (must be exactly binary_len(P))
+///
+/// # Errors
+///
+/// Returns [`DecodeError::Invalid`] if:
+/// - The data is less than 3 bytes, meaning there is no payload
+/// - The data is more than `B` bytes
+/// - The checksum does not match
+pub fn decode_binary<const P: usize, const B: usize>(
+    data: &[u8],
+) -> Result<(u8, Vec<u8, P>), DecodeError> {
+    const {
+        assert!(B == binary_len(P), "B must be exactly binary_len(P)");
+    }
+
+    if !(3..=B).contains(&data.len()) {
+        return Err(DecodeError::Invalid);
+    }
+
     // Unpack version.
     let ver = data[0];
 
@@ -126,8 +180,8 @@ pub fn decode<const P: usize, const B: usize>(s: &[u8]) -> Result<(u8, Vec<u8, P
     }
 
     // Unpack payload.
-    // Safety: unwrap cannot fail because const assertion `P >= B - 3` ensures
-    // P can hold any valid payload (payload_data.len() <= B - 3 <= P).
+    // Safety: unwrap cannot fail because const assertion `B == binary_len(P)`
+    // and the length check ensure the payload is at most B - 3 = P bytes.
     let payload_data = &data_without_crc[1..];
     let payload: Vec<u8, P> = Vec::from_slice(payload_data).unwrap();
     Ok((ver, payload))
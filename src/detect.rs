@@ -0,0 +1,153 @@
+use core::fmt::Display;
+
+use crate::{
+    ed25519, error::DecodeError, Caip10, Caip2, ClaimableBalance, Contract, HashX, LiquidityPool,
+    PreAuthTx, Strkey,
+};
+
+/// The kind of strkey that 32 bytes of hex are, which hex alone does not say.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum HexKind {
+    PublicKey,
+    PrivateKey,
+    PreAuthTx,
+    HashX,
+    Contract,
+    LiquidityPool,
+    ClaimableBalance,
+}
+
+impl HexKind {
+    fn strkey(self, bytes: [u8; 32]) -> Strkey {
+        match self {
+            Self::PublicKey => Strkey::PublicKeyEd25519(ed25519::PublicKey(bytes)),
+            Self::PrivateKey => Strkey::PrivateKeyEd25519(ed25519::PrivateKey(bytes)),
+            Self::PreAuthTx => Strkey::PreAuthTx(PreAuthTx(bytes)),
+            Self::HashX => Strkey::HashX(HashX(bytes)),
+            Self::Contract => Strkey::Contract(Contract(bytes)),
+            Self::LiquidityPool => Strkey::LiquidityPool(LiquidityPool(bytes)),
+            Self::ClaimableBalance => Strkey::ClaimableBalance(ClaimableBalance::V0(bytes)),
+        }
+    }
+}
+
+/// The representation that [`Strkey::detect`] found a strkey in.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Format {
+    /// A strkey.
+    Strkey,
+    /// 64 characters of hex, such as a liquidity pool ID as Horizon reports
+    /// it.
+    Hex,
+    /// 64 characters of hex prefixed with `0x`.
+    PrefixedHex,
+    /// A claimable balance ID in the 72 character hex form Horizon reports.
+    HorizonClaimableBalance,
+    /// Base64 XDR `AccountID`.
+    AccountIdXdr,
+    /// Base64 XDR `MuxedAccount` of a muxed account.
+    MuxedAccountXdr,
+    /// Base64 XDR `SCAddress`.
+    ScAddressXdr,
+    /// A CAIP-10 account ID on the network.
+    Caip10(Caip2),
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Strkey => f.write_str("strkey"),
+            Self::Hex => f.write_str("hex"),
+            Self::PrefixedHex => f.write_str("0x-prefixed hex"),
+            Self::HorizonClaimableBalance => f.write_str("Horizon claimable balance ID"),
+            Self::AccountIdXdr => f.write_str("base64 XDR AccountID"),
+            Self::MuxedAccountXdr => f.write_str("base64 XDR MuxedAccount"),
+            Self::ScAddressXdr => f.write_str("base64 XDR SCAddress"),
+            Self::Caip10(network) => write!(f, "CAIP-10 account ID on {network}"),
+        }
+    }
+}
+
+/// Decodes 64 characters of hex in either case.
+fn decode_hex(s: &str) -> Result<[u8; 32], DecodeError> {
+    if s.len() != 64 {
+        return Err(DecodeError::Invalid);
+    }
+    let mut bytes = [0u8; 32];
+    data_encoding::HEXLOWER_PERMISSIVE
+        .decode_mut(s.as_bytes(), &mut bytes)
+        .map_err(|_| DecodeError::Invalid)?;
+    Ok(bytes)
+}
+
+impl Strkey {
+    /// Parses any common representation of a Stellar identifier, returning
+    /// the strkey and the [`Format`] it was found in.
+    ///
+    /// Accepted, in the order tried:
+    ///
+    /// - A CAIP-10 account ID, `stellar:<network>:<address>`.
+    /// - 64 characters of hex prefixed with `0x`, decoded as `hex_kind`.
+    /// - 72 characters of hex, a claimable balance ID as Horizon reports it.
+    /// - 64 characters of hex, decoded as `hex_kind`.
+    /// - A strkey.
+    /// - With the `xdr` feature, base64 XDR of an `AccountID`, a
+    ///   `MuxedAccount` of a muxed account, or an `SCAddress`.
+    ///
+    /// Leading and trailing whitespace is ignored.
+    ///
+    /// ### Errors
+    ///
+    /// If the input is 64 characters of hex, with or without a `0x` prefix,
+    /// and no `hex_kind` is given, or the input is not any of the accepted
+    /// representations.
+    pub fn detect(s: &str, hex_kind: Option<HexKind>) -> Result<(Self, Format), DecodeError> {
+        let s = s.trim();
+        if s.starts_with("stellar:") {
+            let caip10 = Caip10::from_string(s)?;
+            return Ok((
+                caip10.address().clone(),
+                Format::Caip10(caip10.network().clone()),
+            ));
+        }
+        if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            let bytes = decode_hex(hex)?;
+            let kind = hex_kind.ok_or(DecodeError::AmbiguousKind)?;
+            return Ok((kind.strkey(bytes), Format::PrefixedHex));
+        }
+        match (s.len(), hex_kind) {
+            (ClaimableBalance::HORIZON_HEX_LEN, _) => {
+                if let Ok(balance) = ClaimableBalance::from_horizon_hex(s) {
+                    return Ok((
+                        Strkey::ClaimableBalance(balance),
+                        Format::HorizonClaimableBalance,
+                    ));
+                }
+            }
+            (LiquidityPool::HORIZON_HEX_LEN, _) => {
+                if let Ok(bytes) = decode_hex(s) {
+                    let kind = hex_kind.ok_or(DecodeError::AmbiguousKind)?;
+                    return Ok((kind.strkey(bytes), Format::Hex));
+                }
+            }
+            _ => {}
+        }
+        if let Ok(strkey) = Strkey::from_string(s) {
+            return Ok((strkey, Format::Strkey));
+        }
+        #[cfg(feature = "xdr")]
+        {
+            use crate::Address;
+            if let Ok(key) = ed25519::PublicKey::from_xdr_base64(s) {
+                return Ok((Strkey::PublicKeyEd25519(key), Format::AccountIdXdr));
+            }
+            if let Ok(muxed) = ed25519::MuxedAccount::from_xdr_base64(s) {
+                return Ok((Strkey::MuxedAccountEd25519(muxed), Format::MuxedAccountXdr));
+            }
+            if let Ok(address) = Address::from_xdr_base64(s) {
+                return Ok((address.into(), Format::ScAddressXdr));
+            }
+        }
+        Err(DecodeError::Invalid)
+    }
+}
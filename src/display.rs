@@ -0,0 +1,126 @@
+use core::fmt::{Display, Write};
+
+use heapless::String;
+
+use crate::Strkey;
+
+const MAX_ENCODED_LEN: usize = Strkey::MAX_ENCODED_LEN;
+
+/// Displays a strkey abbreviated to its head and tail, e.g. `GA3D…HES5`.
+///
+/// Returned by [`Strkey::display_short`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayShort {
+    strkey: String<MAX_ENCODED_LEN>,
+    head: usize,
+    tail: usize,
+}
+
+/// Displays a strkey in blocks of characters separated by spaces, e.g.
+/// `GA3D 5KRY M6CB …`.
+///
+/// Returned by [`Strkey::display_grouped`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayGrouped {
+    strkey: String<MAX_ENCODED_LEN>,
+    group_len: usize,
+}
+
+impl Strkey {
+    /// The head and tail lengths that [`Strkey::display_short_default`] uses.
+    pub const DISPLAY_SHORT_DEFAULT: (usize, usize) = (4, 4);
+
+    /// The length of the short IDs that stellar-core logs in place of keys.
+    pub const SHORT_ID_LEN: usize = 5;
+
+    /// Returns the number of trailing characters of the strkey that encode
+    /// any bits of its checksum.
+    pub fn checksum_chars(&self) -> usize {
+        let binary_len = self.to_binary().len();
+        let encoded_len = self.to_string().len();
+        // The checksum is the last 16 bits, and each character encodes 5 bits.
+        encoded_len - (binary_len * 8 - 16) / 5
+    }
+
+    /// Returns a [`Display`] of the strkey abbreviated to the first `head`
+    /// and last `tail` characters, joined by `…`.
+    ///
+    /// The tail is never shorter than [`Strkey::checksum_chars`], so that an
+    /// abbreviation always ends with the full checksum rather than cutting
+    /// inside it. If the abbreviation would not be shorter than the strkey,
+    /// the whole strkey is displayed.
+    pub fn display_short(&self, head: usize, tail: usize) -> DisplayShort {
+        DisplayShort {
+            strkey: self.to_string(),
+            head,
+            tail: tail.max(self.checksum_chars()),
+        }
+    }
+
+    /// Returns a [`Display`] of the strkey abbreviated to its first and last 4
+    /// characters, e.g. `GA3D…HES5`.
+    pub fn display_short_default(&self) -> DisplayShort {
+        let (head, tail) = Self::DISPLAY_SHORT_DEFAULT;
+        self.display_short(head, tail)
+    }
+
+    /// Returns a [`Display`] of the strkey in blocks of `group_len`
+    /// characters separated by spaces, for reading aloud or printing. Blocks
+    /// of 4 or 5 characters are typical, and the last block may be shorter.
+    ///
+    /// A `group_len` of 0 displays the strkey without spaces.
+    pub fn display_grouped(&self, group_len: usize) -> DisplayGrouped {
+        DisplayGrouped {
+            strkey: self.to_string(),
+            group_len,
+        }
+    }
+
+    /// Returns the short ID that stellar-core logs in place of the key, the
+    /// first 5 characters of the strkey, e.g. `GA3D5`.
+    ///
+    /// Short IDs are for recognizing keys in logs, and are not unique.
+    pub fn short_id(&self) -> String<{ Self::SHORT_ID_LEN }> {
+        let strkey = self.to_string();
+        // Safety: unwrap cannot fail because every strkey is longer than the
+        // short ID, and is ASCII.
+        String::try_from(&strkey[..Self::SHORT_ID_LEN]).unwrap()
+    }
+}
+
+impl Display for DisplayShort {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let len = self.strkey.len();
+        // The ellipsis is one character, so abbreviating only shortens the
+        // strkey when more than one character is left out.
+        if self.head.saturating_add(self.tail).saturating_add(1) >= len {
+            return f.pad(&self.strkey);
+        }
+        let mut s = String::<{ MAX_ENCODED_LEN + 2 }>::new();
+        // Safety: unwraps cannot fail because at least 2 characters are left
+        // out, and the ellipsis is 3 bytes of UTF-8.
+        s.push_str(&self.strkey[..self.head]).unwrap();
+        s.push('…').unwrap();
+        s.push_str(&self.strkey[len - self.tail..]).unwrap();
+        f.pad(&s)
+    }
+}
+
+impl Display for DisplayGrouped {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.group_len == 0 {
+            return f.pad(&self.strkey);
+        }
+        // Every character is followed by at most one space.
+        let mut s = String::<{ MAX_ENCODED_LEN * 2 }>::new();
+        for (i, chunk) in self.strkey.as_bytes().chunks(self.group_len).enumerate() {
+            if i > 0 {
+                s.write_char(' ')?;
+            }
+            // Safety: unwrap cannot fail because the strkey is ASCII, so every
+            // chunk is UTF-8.
+            s.write_str(core::str::from_utf8(chunk).unwrap())?;
+        }
+        f.pad(&s)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    convert::{binary_len, decode, encode, encode_len},
-    error::DecodeError,
+    convert::{binary_len, decode, decode_binary, encode, encode_binary, encode_len},
+    error::{DecodeError, SignedPayloadError},
     version,
 };
 
@@ -61,11 +61,60 @@ impl PrivateKey {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::BINARY_LEN }> {
+        encode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(
+            version::PRIVATE_KEY_ED25519,
+            &self.0,
+        )
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) = decode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(b)?;
+        match ver {
+            version::PRIVATE_KEY_ED25519 => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl PrivateKey {
+    /// Derives the public key of the ed25519 key pair for the private key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(
+            ed25519_dalek::SigningKey::from_bytes(&self.0)
+                .verifying_key()
+                .to_bytes(),
+        )
+    }
+
+    /// Converts the private key to the X25519 private key of the same key
+    /// pair on the birationally equivalent Montgomery curve.
+    ///
+    /// The X25519 key is the clamped first half of the SHA-512 hash of the
+    /// ed25519 private key, as computed by libsodium's
+    /// `crypto_sign_ed25519_sk_to_curve25519`.
+    pub fn to_x25519(&self) -> [u8; 32] {
+        let mut x = ed25519_dalek::SigningKey::from_bytes(&self.0).to_scalar_bytes();
+        x[0] &= 248;
+        x[31] &= 127;
+        x[31] |= 64;
+        x
+    }
 }
 
 impl Display for PrivateKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
@@ -160,11 +209,103 @@ impl PublicKey {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::BINARY_LEN }> {
+        encode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(
+            version::PUBLIC_KEY_ED25519,
+            &self.0,
+        )
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) = decode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(b)?;
+        match ver {
+            version::PUBLIC_KEY_ED25519 => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Returns the muxed account with the ID multiplexed from the account.
+    pub fn muxed(&self, id: u64) -> MuxedAccount {
+        MuxedAccount::new(*self, id)
+    }
+}
+
+#[cfg(feature = "curve25519-dalek")]
+impl PublicKey {
+    /// Checks that the public key is the canonical encoding of a point on the
+    /// ed25519 curve that is not of small order.
+    ///
+    /// A strkey with a valid checksum can contain any 32 bytes, but only keys
+    /// that pass this check can have signatures verified for them. Accounts
+    /// with keys that fail this check can be funded, but can never sign.
+    ///
+    /// ### Errors
+    ///
+    /// - [`DecodeError::NotOnCurve`] if the bytes do not decode to a point.
+    /// - [`DecodeError::NonCanonicalPoint`] if the point is not canonically
+    ///   encoded.
+    /// - [`DecodeError::SmallOrderPoint`] if the point is of small order.
+    pub fn check_point(&self) -> Result<(), DecodeError> {
+        let compressed = curve25519_dalek::edwards::CompressedEdwardsY(self.0);
+        let point = compressed.decompress().ok_or(DecodeError::NotOnCurve)?;
+        if point.compress() != compressed {
+            return Err(DecodeError::NonCanonicalPoint);
+        }
+        if point.is_small_order() {
+            return Err(DecodeError::SmallOrderPoint);
+        }
+        Ok(())
+    }
+
+    /// Converts the public key to the X25519 public key of the same key pair
+    /// on the birationally equivalent Montgomery curve, as computed by
+    /// libsodium's `crypto_sign_ed25519_pk_to_curve25519`.
+    ///
+    /// ### Errors
+    ///
+    /// If the public key fails [`PublicKey::check_point`].
+    pub fn to_x25519(&self) -> Result<[u8; 32], DecodeError> {
+        self.check_point()?;
+        let compressed = curve25519_dalek::edwards::CompressedEdwardsY(self.0);
+        let point = compressed.decompress().ok_or(DecodeError::NotOnCurve)?;
+        Ok(point.to_montgomery().to_bytes())
+    }
+
+    /// Returns true if the public key is the canonical encoding of a point on
+    /// the ed25519 curve that is not of small order.
+    ///
+    /// See [`PublicKey::check_point`].
+    pub fn is_canonical_point(&self) -> bool {
+        self.check_point().is_ok()
+    }
+
+    /// Decodes a public key strkey, and checks the key with
+    /// [`PublicKey::check_point`].
+    pub fn from_string_strict(s: &str) -> Result<Self, DecodeError> {
+        Self::from_slice_strict(s.as_bytes())
+    }
+
+    /// Decodes a public key strkey, and checks the key with
+    /// [`PublicKey::check_point`].
+    pub fn from_slice_strict(s: &[u8]) -> Result<Self, DecodeError> {
+        let key = Self::from_slice(s)?;
+        key.check_point()?;
+        Ok(key)
+    }
 }
 
 impl Display for PublicKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
@@ -239,14 +380,18 @@ impl MuxedAccount {
     };
 
     pub fn to_string(&self) -> String<{ Self::ENCODED_LEN }> {
+        encode::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }, { Self::ENCODED_LEN }>(
+            version::MUXED_ACCOUNT_ED25519,
+            &self.payload(),
+        )
+    }
+
+    fn payload(&self) -> [u8; Self::PAYLOAD_LEN] {
         let mut payload: [u8; Self::PAYLOAD_LEN] = [0; Self::PAYLOAD_LEN];
         let (ed25519, id) = payload.split_at_mut(32);
         ed25519.copy_from_slice(&self.ed25519);
         id.copy_from_slice(&self.id.to_be_bytes());
-        encode::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }, { Self::ENCODED_LEN }>(
-            version::MUXED_ACCOUNT_ED25519,
-            &payload,
-        )
+        payload
     }
 
     pub fn from_payload(payload: &[u8]) -> Result<Self, DecodeError> {
@@ -271,11 +416,50 @@ impl MuxedAccount {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::BINARY_LEN }> {
+        encode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(
+            version::MUXED_ACCOUNT_ED25519,
+            &self.payload(),
+        )
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) = decode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(b)?;
+        match ver {
+            version::MUXED_ACCOUNT_ED25519 => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Creates the muxed account with the ID multiplexed from the account.
+    ///
+    /// The ID is the memo ID that identified the same customer before muxed
+    /// accounts, as specified by SEP-23.
+    pub fn new(account: PublicKey, id: u64) -> Self {
+        Self {
+            ed25519: account.0,
+            id,
+        }
+    }
+
+    /// Returns the account the muxed account is multiplexed from.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(self.ed25519)
+    }
 }
 
 impl Display for MuxedAccount {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
@@ -287,6 +471,18 @@ impl FromStr for MuxedAccount {
     }
 }
 
+impl From<(PublicKey, u64)> for MuxedAccount {
+    fn from((account, id): (PublicKey, u64)) -> Self {
+        Self::new(account, id)
+    }
+}
+
+impl From<MuxedAccount> for (PublicKey, u64) {
+    fn from(muxed: MuxedAccount) -> Self {
+        (muxed.public_key(), muxed.id)
+    }
+}
+
 #[cfg(feature = "serde-decoded")]
 mod muxed_account_decoded_serde_impl {
     use super::*;
@@ -353,6 +549,9 @@ impl Debug for SignedPayload {
 }
 
 impl SignedPayload {
+    /// The largest payload a signed payload signer can have.
+    pub const MAX_INNER_PAYLOAD_LEN: usize = 64;
+
     // Max payload: 32 ed25519 + 4 len + 64 inner payload = 100
     pub(crate) const MAX_PAYLOAD_LEN: usize = 32 + 4 + 64;
     pub(crate) const MAX_BINARY_LEN: usize = binary_len(Self::MAX_PAYLOAD_LEN);
@@ -363,8 +562,74 @@ impl SignedPayload {
         assert!(Self::MAX_ENCODED_LEN == 165);
     };
 
+    /// Creates a signed payload signer for the ed25519 public key and payload.
+    ///
+    /// ### Errors
+    ///
+    /// If the payload is empty or longer than 64 bytes.
+    pub fn new(ed25519: PublicKey, payload: &[u8]) -> Result<Self, SignedPayloadError> {
+        if payload.is_empty() {
+            return Err(SignedPayloadError::PayloadEmpty);
+        }
+        let payload = Vec::from_slice(payload).map_err(|()| SignedPayloadError::PayloadTooLong)?;
+        Ok(Self {
+            ed25519: ed25519.0,
+            payload,
+        })
+    }
+
+    /// Returns the ed25519 public key that signs the payload.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(self.ed25519)
+    }
+
+    /// Returns the hint of a decorated signature for the signer.
+    ///
+    /// As defined by CAP-40, the hint is the last 4 bytes of the ed25519
+    /// public key XORed with the last 4 bytes of the payload, where a payload
+    /// shorter than 4 bytes is padded with zeros on the right.
+    pub fn hint(&self) -> [u8; 4] {
+        let mut payload_hint = [0u8; 4];
+        let len = self.payload.len();
+        if len >= 4 {
+            payload_hint.copy_from_slice(&self.payload[len - 4..]);
+        } else {
+            payload_hint[..len].copy_from_slice(&self.payload);
+        }
+        let mut hint = [0u8; 4];
+        for (i, b) in hint.iter_mut().enumerate() {
+            *b = self.ed25519[28 + i] ^ payload_hint[i];
+        }
+        hint
+    }
+
+    /// Returns true if the signature is an ed25519 signature over the payload
+    /// by the public key, and so satisfies the signer.
+    ///
+    /// The payload is signed as is, without hashing or prefixing. Verification
+    /// is strict, rejecting public keys of small order and non-canonical
+    /// signatures.
+    #[cfg(feature = "ed25519-dalek")]
+    pub fn is_satisfied_by(&self, signature: &[u8; 64]) -> bool {
+        let Ok(key) = ed25519_dalek::VerifyingKey::from_bytes(&self.ed25519) else {
+            return false;
+        };
+        key.verify_strict(
+            &self.payload,
+            &ed25519_dalek::Signature::from_bytes(signature),
+        )
+        .is_ok()
+    }
+
     /// Returns the strkey string for the signed payload signer.
     pub fn to_string(&self) -> String<{ Self::MAX_ENCODED_LEN }> {
+        encode::<{ Self::MAX_PAYLOAD_LEN }, { Self::MAX_BINARY_LEN }, { Self::MAX_ENCODED_LEN }>(
+            version::SIGNED_PAYLOAD_ED25519,
+            &self.payload(),
+        )
+    }
+
+    fn payload(&self) -> Vec<u8, { Self::MAX_PAYLOAD_LEN }> {
         let inner_payload_len = self.payload.len();
         let payload_len = 32 + 4 + inner_payload_len + (4 - inner_payload_len % 4) % 4;
 
@@ -376,10 +641,9 @@ impl SignedPayload {
         payload[32..32 + 4].copy_from_slice(&(inner_payload_len_u32).to_be_bytes());
         payload[32 + 4..32 + 4 + inner_payload_len].copy_from_slice(&self.payload);
 
-        encode::<{ Self::MAX_PAYLOAD_LEN }, { Self::MAX_BINARY_LEN }, { Self::MAX_ENCODED_LEN }>(
-            version::SIGNED_PAYLOAD_ED25519,
-            &payload[..payload_len],
-        )
+        // Safety: unwrap cannot fail because payload_len is at most
+        // MAX_PAYLOAD_LEN.
+        Vec::from_slice(&payload[..payload_len]).unwrap()
     }
 
     /// Decodes a signed payload from raw bytes.
@@ -390,7 +654,7 @@ impl SignedPayload {
     pub fn from_payload(payload: &[u8]) -> Result<Self, DecodeError> {
         // 32-byte for the signer, 4-byte for the payload size, then either 4-byte for the
         // min or 64-byte for the max payload
-        const MAX_INNER_PAYLOAD_LENGTH: u32 = 64;
+        const MAX_INNER_PAYLOAD_LENGTH: u32 = SignedPayload::MAX_INNER_PAYLOAD_LEN as u32;
         const MIN_LENGTH: usize = 32 + 4 + 4;
         const MAX_LENGTH: usize = 32 + 4 + (MAX_INNER_PAYLOAD_LENGTH as usize);
         let payload_len = payload.len();
@@ -465,11 +729,35 @@ impl SignedPayload {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::MAX_BINARY_LEN }> {
+        encode_binary::<{ Self::MAX_PAYLOAD_LEN }, { Self::MAX_BINARY_LEN }>(
+            version::SIGNED_PAYLOAD_ED25519,
+            &self.payload(),
+        )
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) =
+            decode_binary::<{ Self::MAX_PAYLOAD_LEN }, { Self::MAX_BINARY_LEN }>(b)?;
+        match ver {
+            version::SIGNED_PAYLOAD_ED25519 => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
 }
 
 impl Display for SignedPayload {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
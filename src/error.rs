@@ -1,15 +1,83 @@
+/// Errors decoding a strkey or another representation of a key.
+///
+/// More failure modes may be added in later releases, so matches must have a
+/// wildcard arm.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[non_exhaustive]
 pub enum DecodeError {
     // TODO: Add meaningful errors for each problem that can occur.
     Invalid,
+    /// The ed25519 public key is not the encoding of a point on the curve.
+    NotOnCurve,
+    /// The ed25519 public key is a point on the curve, but not in its
+    /// canonical encoding.
+    NonCanonicalPoint,
+    /// The ed25519 public key is a point of small order, for which no
+    /// private key can produce valid signatures.
+    SmallOrderPoint,
+    /// The strkey is valid, but of a kind that is not accepted, e.g. a private
+    /// key where an address is expected.
+    UnexpectedKind,
+    /// The input is valid, but does not say what kind of key it is, and no
+    /// kind was given, e.g. hex.
+    AmbiguousKind,
 }
 
 impl core::fmt::Display for DecodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
-            DecodeError::Invalid {} => f.write_str("the strkey is invalid"),
+            DecodeError::Invalid => f.write_str("the strkey is invalid"),
+            DecodeError::NotOnCurve => {
+                f.write_str("the ed25519 public key is not a point on the curve")
+            }
+            DecodeError::NonCanonicalPoint => {
+                f.write_str("the ed25519 public key is not canonically encoded")
+            }
+            DecodeError::SmallOrderPoint => {
+                f.write_str("the ed25519 public key is a point of small order")
+            }
+            DecodeError::UnexpectedKind => f.write_str("the strkey is not of an accepted kind"),
+            DecodeError::AmbiguousKind => f.write_str("the kind of key is ambiguous"),
         }
     }
 }
 
 impl core::error::Error for DecodeError {}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum LiquidityPoolIdError {
+    AssetsIdentical,
+    AssetsOutOfOrder,
+}
+
+impl core::fmt::Display for LiquidityPoolIdError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            LiquidityPoolIdError::AssetsIdentical => f.write_str("the assets are identical"),
+            LiquidityPoolIdError::AssetsOutOfOrder => {
+                f.write_str("the assets are not in canonical order")
+            }
+        }
+    }
+}
+
+impl core::error::Error for LiquidityPoolIdError {}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum SignedPayloadError {
+    PayloadEmpty,
+    PayloadTooLong,
+}
+
+impl core::fmt::Display for SignedPayloadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            SignedPayloadError::PayloadEmpty => f.write_str("the payload is empty"),
+            SignedPayloadError::PayloadTooLong => {
+                f.write_str("the payload is longer than 64 bytes")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SignedPayloadError {}
@@ -0,0 +1,92 @@
+//! Conversions between the strkey types and the hex IDs that Horizon and
+//! stellar-core report for claimable balances and liquidity pools.
+//!
+//! A claimable balance ID is the hex XDR `ClaimableBalanceID`, the 4 byte
+//! discriminant followed by the 32 byte hash, 72 characters in all. A
+//! liquidity pool ID is the hex 32 byte `PoolID`, 64 characters.
+//!
+//! Hex is emitted lowercase, as Horizon does, and accepted in either case.
+
+use data_encoding::{HEXLOWER, HEXLOWER_PERMISSIVE};
+use heapless::String;
+
+use crate::{error::DecodeError, ClaimableBalance, LiquidityPool};
+
+// ClaimableBalanceIDType
+const CLAIMABLE_BALANCE_ID_TYPE_V0: u32 = 0;
+
+/// Encodes the bytes as lowercase hex.
+fn encode_hex<const N: usize, const HEX_LEN: usize>(bytes: &[u8; N]) -> String<HEX_LEN> {
+    let mut buf = [0u8; HEX_LEN];
+    HEXLOWER.encode_mut(bytes, &mut buf);
+    let mut s = String::new();
+    // Safety: unwraps cannot fail because hex is ASCII and HEX_LEN is twice N.
+    s.push_str(core::str::from_utf8(&buf).unwrap()).unwrap();
+    s
+}
+
+/// Decodes exactly `N` bytes from hex in either case.
+fn decode_hex<const N: usize>(s: &str) -> Result<[u8; N], DecodeError> {
+    if s.len() != N * 2 {
+        return Err(DecodeError::Invalid);
+    }
+    let mut bytes = [0u8; N];
+    HEXLOWER_PERMISSIVE
+        .decode_mut(s.as_bytes(), &mut bytes)
+        .map_err(|_| DecodeError::Invalid)?;
+    Ok(bytes)
+}
+
+impl ClaimableBalance {
+    /// The length of a claimable balance ID in Horizon's hex form.
+    pub const HORIZON_HEX_LEN: usize = 72;
+
+    /// Returns the claimable balance ID in the hex form Horizon and
+    /// stellar-core report, `00000000` followed by the hex hash.
+    pub fn to_horizon_hex(&self) -> String<{ Self::HORIZON_HEX_LEN }> {
+        let Self::V0(hash) = self;
+        let mut bytes = [0u8; Self::HORIZON_HEX_LEN / 2];
+        bytes[..4].copy_from_slice(&CLAIMABLE_BALANCE_ID_TYPE_V0.to_be_bytes());
+        bytes[4..].copy_from_slice(hash);
+        encode_hex(&bytes)
+    }
+
+    /// Decodes a claimable balance ID in the hex form Horizon and
+    /// stellar-core report.
+    ///
+    /// ### Errors
+    ///
+    /// If the string is not 72 hex characters, or the discriminant is not of
+    /// a version 0 ID.
+    pub fn from_horizon_hex(s: &str) -> Result<Self, DecodeError> {
+        let bytes = decode_hex::<{ Self::HORIZON_HEX_LEN / 2 }>(s)?;
+        let (discriminant, hash) = bytes.split_at(4);
+        if discriminant != CLAIMABLE_BALANCE_ID_TYPE_V0.to_be_bytes() {
+            return Err(DecodeError::Invalid);
+        }
+        // Safety: unwrap cannot fail because the hash is the remaining 32
+        // bytes.
+        Ok(Self::V0(hash.try_into().unwrap()))
+    }
+}
+
+impl LiquidityPool {
+    /// The length of a liquidity pool ID in Horizon's hex form.
+    pub const HORIZON_HEX_LEN: usize = 64;
+
+    /// Returns the liquidity pool ID in the hex form Horizon and stellar-core
+    /// report.
+    pub fn to_horizon_hex(&self) -> String<{ Self::HORIZON_HEX_LEN }> {
+        encode_hex(&self.0)
+    }
+
+    /// Decodes a liquidity pool ID in the hex form Horizon and stellar-core
+    /// report.
+    ///
+    /// ### Errors
+    ///
+    /// If the string is not 64 hex characters.
+    pub fn from_horizon_hex(s: &str) -> Result<Self, DecodeError> {
+        Ok(Self(decode_hex(s)?))
+    }
+}
@@ -0,0 +1,239 @@
+//! Password encrypted keystore files for ed25519 private keys.
+//!
+//! A keystore is a versioned JSON document that stores an ed25519 private key
+//! encrypted under a key derived from a password, alongside the `G` strkey of
+//! the public key in clear so that the file can be identified without the
+//! password:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "address": "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5",
+//!   "kdf": {
+//!     "scrypt": {
+//!       "log_n": 17,
+//!       "r": 8,
+//!       "p": 1,
+//!       "salt": "<32 bytes hex>"
+//!     }
+//!   },
+//!   "cipher": {
+//!     "chacha20poly1305": {
+//!       "nonce": "<12 bytes hex>"
+//!     }
+//!   },
+//!   "ciphertext": "<48 bytes hex>"
+//! }
+//! ```
+//!
+//! Version 1 of the format is defined as:
+//!
+//! 1. A 32 byte key is derived from the UTF-8 password with scrypt, using the
+//!    `log_n`, `r` and `p` cost parameters and the random 32 byte `salt`.
+//! 2. The 32 byte ed25519 private key is encrypted with ChaCha20-Poly1305
+//!    (RFC 8439) under the derived key and the random 12 byte `nonce`. The 32
+//!    byte ed25519 public key is the associated data, which binds the
+//!    `address` to the ciphertext.
+//! 3. The `ciphertext` is the 32 byte encrypted private key followed by the 16
+//!    byte authentication tag.
+//!
+//! Decryption fails if the password is wrong, if any field has been modified,
+//! or if the decrypted private key does not belong to the `address`.
+
+use chacha20poly1305::{
+    aead::{AeadInPlace, KeyInit},
+    ChaCha20Poly1305,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::ed25519;
+
+/// Errors that can occur encrypting or decrypting a keystore.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Error {
+    /// The keystore version is not supported by this crate.
+    UnsupportedVersion(u32),
+    /// The KDF parameters are invalid or exceed the supported cost.
+    InvalidKdfParams,
+    /// The password is incorrect or the keystore has been modified.
+    Decrypt,
+    /// The decrypted private key does not belong to the keystore address.
+    AddressMismatch,
+    /// Random bytes for the salt or nonce could not be generated.
+    Random,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::UnsupportedVersion(v) => write!(f, "unsupported keystore version {v}"),
+            Error::InvalidKdfParams => f.write_str("invalid kdf parameters"),
+            Error::Decrypt => f.write_str("incorrect password or corrupted keystore"),
+            Error::AddressMismatch => f.write_str("private key does not match the address"),
+            Error::Random => f.write_str("failed to generate random bytes"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    pub address: ed25519::PublicKey,
+    pub kdf: Kdf,
+    pub cipher: Cipher,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub ciphertext: [u8; 48],
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kdf {
+    Scrypt(ScryptParams),
+}
+
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScryptParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub salt: [u8; 32],
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cipher {
+    #[serde(rename = "chacha20poly1305")]
+    ChaCha20Poly1305(ChaCha20Poly1305Params),
+}
+
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChaCha20Poly1305Params {
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub nonce: [u8; 12],
+}
+
+impl ScryptParams {
+    pub const DEFAULT_LOG_N: u8 = 17;
+    pub const DEFAULT_R: u32 = 8;
+    pub const DEFAULT_P: u32 = 1;
+    /// The largest `log_n` accepted.
+    pub const MAX_LOG_N: u8 = 20;
+    /// The largest `r` accepted.
+    pub const MAX_R: u32 = 8;
+    /// The largest `p` accepted.
+    pub const MAX_P: u32 = 1;
+
+    /// Derives the key, rejecting parameters above [`ScryptParams::MAX_LOG_N`],
+    /// [`ScryptParams::MAX_R`] and [`ScryptParams::MAX_P`]. Scrypt uses
+    /// `128 * r * 2^log_n` bytes of memory and `p` times the work, so the
+    /// limits bound what an untrusted keystore can make decryption use to
+    /// 1 GiB of memory and a single pass.
+    fn derive_key(&self, password: &[u8]) -> Result<[u8; 32], Error> {
+        if self.log_n > Self::MAX_LOG_N || self.r > Self::MAX_R || self.p > Self::MAX_P {
+            return Err(Error::InvalidKdfParams);
+        }
+        let params = scrypt::Params::new(self.log_n, self.r, self.p, 32)
+            .map_err(|_| Error::InvalidKdfParams)?;
+        let mut key = [0u8; 32];
+        scrypt::scrypt(password, &self.salt, &params, &mut key)
+            .map_err(|_| Error::InvalidKdfParams)?;
+        Ok(key)
+    }
+}
+
+impl Keystore {
+    pub const VERSION: u32 = 1;
+
+    /// Encrypts the private key under the password, using the default scrypt
+    /// cost parameters and a random salt and nonce.
+    pub fn encrypt(key: &ed25519::PrivateKey, password: &[u8]) -> Result<Self, Error> {
+        Self::encrypt_with_cost(
+            key,
+            password,
+            ScryptParams::DEFAULT_LOG_N,
+            ScryptParams::DEFAULT_R,
+            ScryptParams::DEFAULT_P,
+        )
+    }
+
+    /// Encrypts the private key under the password, using the scrypt cost
+    /// parameters and a random salt and nonce.
+    pub fn encrypt_with_cost(
+        key: &ed25519::PrivateKey,
+        password: &[u8],
+        log_n: u8,
+        r: u32,
+        p: u32,
+    ) -> Result<Self, Error> {
+        let mut salt = [0u8; 32];
+        let mut nonce = [0u8; 12];
+        getrandom::getrandom(&mut salt).map_err(|_| Error::Random)?;
+        getrandom::getrandom(&mut nonce).map_err(|_| Error::Random)?;
+        Self::encrypt_with_salt_and_nonce(key, password, ScryptParams { log_n, r, p, salt }, nonce)
+    }
+
+    /// Encrypts the private key under the password, using the given scrypt
+    /// parameters and nonce.
+    ///
+    /// This is intended for producing known answer tests. Use
+    /// [`Keystore::encrypt`] to encrypt keys, which generates a random salt
+    /// and nonce for each keystore.
+    pub fn encrypt_with_salt_and_nonce(
+        key: &ed25519::PrivateKey,
+        password: &[u8],
+        params: ScryptParams,
+        nonce: [u8; 12],
+    ) -> Result<Self, Error> {
+        let address = key.public_key();
+        let derived = params.derive_key(password)?;
+        let mut ciphertext = [0u8; 48];
+        let (data, tag) = ciphertext.split_at_mut(32);
+        data.copy_from_slice(&key.0);
+        // Safety: unwrap cannot fail because encryption only fails for
+        // plaintexts larger than the cipher's limit, and the key is 32 bytes.
+        let t = ChaCha20Poly1305::new(&derived.into())
+            .encrypt_in_place_detached(&nonce.into(), &address.0, data)
+            .unwrap();
+        tag.copy_from_slice(&t);
+        Ok(Self {
+            version: Self::VERSION,
+            address,
+            kdf: Kdf::Scrypt(params),
+            cipher: Cipher::ChaCha20Poly1305(ChaCha20Poly1305Params { nonce }),
+            ciphertext,
+        })
+    }
+
+    /// Decrypts the private key with the password.
+    ///
+    /// ### Errors
+    ///
+    /// If the version or parameters are not supported, if the password is
+    /// incorrect, if the keystore has been modified, or if the private key does
+    /// not belong to the address.
+    pub fn decrypt(&self, password: &[u8]) -> Result<ed25519::PrivateKey, Error> {
+        if self.version != Self::VERSION {
+            return Err(Error::UnsupportedVersion(self.version));
+        }
+        let Kdf::Scrypt(params) = &self.kdf;
+        let Cipher::ChaCha20Poly1305(ChaCha20Poly1305Params { nonce }) = &self.cipher;
+        let derived = params.derive_key(password)?;
+        let (data, tag) = self.ciphertext.split_at(32);
+        let mut key = [0u8; 32];
+        key.copy_from_slice(data);
+        ChaCha20Poly1305::new(&derived.into())
+            .decrypt_in_place_detached(&(*nonce).into(), &self.address.0, &mut key, tag.into())
+            .map_err(|_| Error::Decrypt)?;
+        let key = ed25519::PrivateKey(key);
+        if key.public_key() != self.address {
+            return Err(Error::AddressMismatch);
+        }
+        Ok(key)
+    }
+}
@@ -0,0 +1,119 @@
+use heapless::Vec;
+
+use crate::{ed25519, error::DecodeError, ClaimableBalance, Contract, LiquidityPool};
+
+/// The key of a ledger entry identified by a strkey, as passed to RPC
+/// `getLedgerEntries`.
+// Data keys are stored inline rather than boxed so that ledger keys are
+// available without an allocator.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LedgerKey {
+    Account(ed25519::PublicKey),
+    ClaimableBalance(ClaimableBalance),
+    LiquidityPool(LiquidityPool),
+    ContractData {
+        contract: Contract,
+        key: ContractDataKey,
+        durability: ContractDataDurability,
+    },
+}
+
+/// The key of a contract data entry within a contract's storage.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ContractDataKey {
+    /// The contract instance entry, which holds the contract's executable and
+    /// instance storage.
+    Instance,
+    /// The XDR `SCVal` of a key the contract stores data under.
+    Data(Vec<u8, { ContractDataKey::MAX_XDR_LEN }>),
+}
+
+/// The durability of a contract data entry.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ContractDataDurability {
+    Temporary,
+    Persistent,
+}
+
+impl ContractDataKey {
+    /// The longest `SCVal` XDR accepted as a data key. The network limits the
+    /// size of contract data ledger keys with its `contract_data_key_size_bytes`
+    /// setting, which is well below this.
+    pub const MAX_XDR_LEN: usize = 1024;
+
+    /// Returns the key for the XDR `SCVal`.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is empty, not a multiple of 4 bytes as all XDR is, or longer
+    /// than [`ContractDataKey::MAX_XDR_LEN`]. The `SCVal` is not otherwise
+    /// validated.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        if xdr.is_empty() || xdr.len() % 4 != 0 {
+            return Err(DecodeError::Invalid);
+        }
+        Ok(Self::Data(
+            Vec::from_slice(xdr).map_err(|()| DecodeError::Invalid)?,
+        ))
+    }
+
+    /// Returns the key for an `SCVal` symbol, the most common kind of data
+    /// key.
+    ///
+    /// ### Errors
+    ///
+    /// If the symbol is longer than 32 characters, or contains characters
+    /// other than `a-z`, `A-Z`, `0-9` and `_`.
+    pub fn symbol(symbol: &str) -> Result<Self, DecodeError> {
+        const SCV_SYMBOL: u32 = 15;
+        const MAX_SYMBOL_LEN: usize = 32;
+        if symbol.len() > MAX_SYMBOL_LEN
+            || !symbol
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+        {
+            return Err(DecodeError::Invalid);
+        }
+        let mut xdr = Vec::<u8, { Self::MAX_XDR_LEN }>::new();
+        // Safety: unwraps cannot fail because the symbol is at most 32 bytes,
+        // so the XDR is at most 40 bytes.
+        xdr.extend_from_slice(&SCV_SYMBOL.to_be_bytes()).unwrap();
+        xdr.extend_from_slice(&(symbol.len() as u32).to_be_bytes())
+            .unwrap();
+        xdr.extend_from_slice(symbol.as_bytes()).unwrap();
+        xdr.resize(xdr.len().next_multiple_of(4), 0).unwrap();
+        Ok(Self::Data(xdr))
+    }
+}
+
+impl LedgerKey {
+    /// Returns the key of the contract's instance entry, which is always
+    /// persistent.
+    pub fn contract_instance(contract: Contract) -> Self {
+        Self::ContractData {
+            contract,
+            key: ContractDataKey::Instance,
+            durability: ContractDataDurability::Persistent,
+        }
+    }
+}
+
+impl From<ed25519::PublicKey> for LedgerKey {
+    fn from(account: ed25519::PublicKey) -> Self {
+        Self::Account(account)
+    }
+}
+
+impl From<ClaimableBalance> for LedgerKey {
+    fn from(balance: ClaimableBalance) -> Self {
+        Self::ClaimableBalance(balance)
+    }
+}
+
+impl From<LiquidityPool> for LedgerKey {
+    fn from(pool: LiquidityPool) -> Self {
+        Self::LiquidityPool(pool)
+    }
+}
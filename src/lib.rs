@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "cli"), no_std)]
 
-#[cfg(feature = "serde-decoded")]
+#[cfg(any(feature = "serde-decoded", feature = "seal", feature = "key-formats"))]
 extern crate alloc;
 
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
@@ -13,15 +13,39 @@ pub const VERSION: Version = Version {
     rev: env!("GIT_REVISION"),
 };
 
+mod address;
+mod asset;
+mod caip;
 mod convert;
 mod crc;
+mod detect;
+mod display;
 pub mod ed25519;
 mod error;
+mod horizon;
+#[cfg(feature = "xdr")]
+mod ledger_key;
+#[cfg(feature = "sha2")]
+mod liquidity_pool_id;
+mod lookalike;
+mod signer_key;
 mod strkey;
 mod typ;
 mod version;
+pub mod well_known;
+#[cfg(feature = "xdr")]
+mod xdr;
 
+pub use address::*;
+pub use asset::*;
+pub use caip::*;
+pub use detect::*;
+pub use display::*;
 pub use error::*;
+#[cfg(feature = "xdr")]
+pub use ledger_key::*;
+pub use lookalike::*;
+pub use signer_key::*;
 pub use strkey::*;
 
 #[cfg(feature = "serde-decoded")]
@@ -29,5 +53,20 @@ pub mod decoded_json_format;
 #[cfg(feature = "serde-decoded")]
 pub use decoded_json_format::Decoded;
 
+#[cfg(feature = "keystore")]
+pub mod keystore;
+
+#[cfg(feature = "seal")]
+pub mod seal;
+
+#[cfg(feature = "message")]
+pub mod message;
+
+#[cfg(feature = "shares")]
+pub mod shares;
+
+#[cfg(feature = "key-formats")]
+pub mod key_formats;
+
 #[cfg(feature = "cli")]
 pub mod cli;
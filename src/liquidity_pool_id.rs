@@ -0,0 +1,45 @@
+use heapless::Vec;
+use sha2::{Digest, Sha256};
+
+use crate::{asset::Asset, error::LiquidityPoolIdError, LiquidityPool};
+
+impl LiquidityPool {
+    /// The fee, in basis points, of constant product pools since protocol 18.
+    pub const FEE_V18: i32 = 30;
+
+    // XDR: 4 pool type + 2 assets + 4 fee = 112
+    const PARAMS_XDR_LEN: usize = 4 + 2 * Asset::MAX_XDR_LEN + 4;
+
+    /// Derives the ID of the constant product liquidity pool for the asset
+    /// pair and fee.
+    ///
+    /// The ID is the SHA-256 hash of the XDR `LiquidityPoolParameters`.
+    ///
+    /// ### Errors
+    ///
+    /// If the assets are identical, or if `asset_a` does not sort before
+    /// `asset_b` in the protocol's canonical asset ordering.
+    pub fn from_constant_product(
+        asset_a: &Asset,
+        asset_b: &Asset,
+        fee: i32,
+    ) -> Result<Self, LiquidityPoolIdError> {
+        if asset_a == asset_b {
+            return Err(LiquidityPoolIdError::AssetsIdentical);
+        }
+        if asset_a > asset_b {
+            return Err(LiquidityPoolIdError::AssetsOutOfOrder);
+        }
+
+        // Safety: unwraps cannot fail because PARAMS_XDR_LEN is the maximum
+        // length of the encoded parameters.
+        let mut xdr: Vec<u8, { Self::PARAMS_XDR_LEN }> = Vec::new();
+        // LIQUIDITY_POOL_CONSTANT_PRODUCT
+        xdr.extend_from_slice(&0u32.to_be_bytes()).unwrap();
+        asset_a.write_xdr(&mut xdr).unwrap();
+        asset_b.write_xdr(&mut xdr).unwrap();
+        xdr.extend_from_slice(&fee.to_be_bytes()).unwrap();
+
+        Ok(Self(Sha256::digest(&xdr).into()))
+    }
+}
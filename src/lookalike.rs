@@ -0,0 +1,64 @@
+use crate::Strkey;
+
+/// How many leading and trailing characters two strkeys share, the
+/// characters that wallets show when they abbreviate a strkey to
+/// `GABC…WXYZ`.
+///
+/// Address poisoning scams send from addresses generated to share the
+/// leading and trailing characters of an address the victim has used, so
+/// that copying the wrong address from a transaction history goes unnoticed.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Similarity {
+    /// The number of leading characters shared, including the version
+    /// character.
+    pub head: usize,
+    /// The number of trailing characters shared.
+    pub tail: usize,
+}
+
+impl Similarity {
+    /// The number of leading and trailing characters that
+    /// [`Strkey::lookalike`] requires by default, matching wallets that
+    /// show `GABC…WXYZ`.
+    pub const DEFAULT_MIN_MATCH: usize = 4;
+
+    /// Returns the similarity score, the total number of leading and trailing
+    /// characters shared. Each character encodes 5 bits, so each point of
+    /// score beyond the version character multiplies the work of generating
+    /// the lookalike by 32.
+    pub fn score(&self) -> usize {
+        self.head + self.tail
+    }
+}
+
+impl Strkey {
+    /// Returns how many leading and trailing characters the strkeys share.
+    ///
+    /// Identical strkeys share all their characters as both head and tail.
+    pub fn similarity(&self, other: &Strkey) -> Similarity {
+        let a = self.to_string();
+        let b = other.to_string();
+        let head = a.bytes().zip(b.bytes()).take_while(|(a, b)| a == b).count();
+        let tail = a
+            .bytes()
+            .rev()
+            .zip(b.bytes().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        Similarity { head, tail }
+    }
+
+    /// Returns the similarity if the strkey looks like the known strkey,
+    /// sharing at least `min_match` leading and at least `min_match` trailing
+    /// characters with it, without being it.
+    ///
+    /// A strkey that does is likely an address poisoning attempt against the
+    /// known strkey.
+    pub fn lookalike(&self, known: &Strkey, min_match: usize) -> Option<Similarity> {
+        if self == known {
+            return None;
+        }
+        let similarity = self.similarity(known);
+        (similarity.head >= min_match && similarity.tail >= min_match).then_some(similarity)
+    }
+}
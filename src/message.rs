@@ -0,0 +1,73 @@
+//! Signing and verifying arbitrary messages, as specified by [SEP-53].
+//!
+//! The message is prefixed with `Stellar Signed Message:\n` and hashed with
+//! SHA-256, and the hash is signed with the ed25519 private key of the `S`
+//! strkey. The prefix stops a signed message from also being a valid signature
+//! over a transaction. Wallets such as Freighter produce signatures in this
+//! format.
+//!
+//! [SEP-53]: https://github.com/stellar/stellar-protocol/blob/master/ecosystem/sep-0053.md
+
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use crate::ed25519;
+
+/// The prefix prepended to messages before they are hashed.
+pub const PREFIX: &[u8] = b"Stellar Signed Message:\n";
+
+/// Errors that can occur verifying a signed message.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Error {
+    /// The public key is not a valid ed25519 curve point.
+    InvalidPublicKey,
+    /// The signature is not a signature over the message by the public key.
+    InvalidSignature,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::InvalidPublicKey => f.write_str("invalid ed25519 public key"),
+            Error::InvalidSignature => f.write_str("signature does not match the message and key"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Returns the SHA-256 hash of the prefixed message, which is the data that is
+/// signed.
+pub fn hash(message: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(PREFIX)
+        .chain_update(message)
+        .finalize()
+        .into()
+}
+
+/// Signs the message with the private key.
+pub fn sign(key: &ed25519::PrivateKey, message: &[u8]) -> [u8; 64] {
+    SigningKey::from_bytes(&key.0)
+        .sign(&hash(message))
+        .to_bytes()
+}
+
+/// Verifies that the signature is a signature over the message by the public
+/// key.
+///
+/// Verification is strict, rejecting public keys of small order and
+/// non-canonical signatures.
+///
+/// ### Errors
+///
+/// If the public key is not a valid curve point, or if the signature does not
+/// match.
+pub fn verify(key: &ed25519::PublicKey, message: &[u8], signature: &[u8; 64]) -> Result<(), Error> {
+    let key = VerifyingKey::from_bytes(&key.0).map_err(|_| Error::InvalidPublicKey)?;
+    key.verify_strict(
+        &hash(message),
+        &ed25519_dalek::Signature::from_bytes(signature),
+    )
+    .map_err(|_| Error::InvalidSignature)
+}
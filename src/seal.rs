@@ -0,0 +1,66 @@
+//! Anonymous public key encryption to ed25519 keys.
+//!
+//! Messages are sealed to the X25519 conversion of a `G` strkey's ed25519
+//! public key, and can be opened only with the X25519 conversion of the
+//! matching `S` strkey's ed25519 private key.
+//!
+//! The ciphertext is a libsodium sealed box (`crypto_box_seal`): a random
+//! ephemeral X25519 public key, followed by the message encrypted with
+//! XSalsa20-Poly1305 under the shared key of the ephemeral key and the
+//! recipient's key, using the BLAKE2b hash of both public keys as the nonce.
+//! Ciphertexts are 48 bytes longer than the message.
+
+use alloc::vec::Vec;
+
+use crate::{ed25519, error::DecodeError};
+
+/// The number of bytes a sealed ciphertext is longer than its message.
+pub const OVERHEAD: usize = crypto_box::SEALBYTES;
+
+/// Errors that can occur sealing or opening a message.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Error {
+    /// The recipient's public key cannot be converted to an X25519 key.
+    InvalidPublicKey(DecodeError),
+    /// The message could not be encrypted.
+    Seal,
+    /// The ciphertext was not sealed to the private key, or was modified.
+    Open,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::InvalidPublicKey(e) => write!(f, "invalid recipient public key: {e}"),
+            Error::Seal => f.write_str("failed to seal message"),
+            Error::Open => f.write_str("ciphertext is not sealed to the key or was modified"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Encrypts the message so that only the holder of the private key of `to`
+/// can decrypt it.
+///
+/// ### Errors
+///
+/// If the public key is not a valid ed25519 curve point, see
+/// [`ed25519::PublicKey::check_point`].
+pub fn seal(to: &ed25519::PublicKey, message: &[u8]) -> Result<Vec<u8>, Error> {
+    let x25519 = to.to_x25519().map_err(Error::InvalidPublicKey)?;
+    crypto_box::PublicKey::from_bytes(x25519)
+        .seal(&mut crypto_box::aead::OsRng, message)
+        .map_err(|_| Error::Seal)
+}
+
+/// Decrypts a ciphertext produced by [`seal`] for the public key of `key`.
+///
+/// ### Errors
+///
+/// If the ciphertext was not sealed to the key, or has been modified.
+pub fn open(key: &ed25519::PrivateKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+    crypto_box::SecretKey::from_bytes(key.to_x25519())
+        .unseal(ciphertext)
+        .map_err(|_| Error::Open)
+}
@@ -0,0 +1,318 @@
+//! Splitting ed25519 private keys into shares with Shamir's secret sharing.
+//!
+//! A private key is split into `n` shares, any `k` of which recombine into the
+//! private key, while fewer than `k` reveal nothing about it.
+//!
+//! Each of the 32 bytes of the private key is the constant term of a random
+//! polynomial of degree `k - 1` over GF(2^8), using the AES reduction
+//! polynomial `x^8 + x^4 + x^3 + x + 1`. The share with index `x`, from 1 to
+//! `n`, holds the 32 values of the polynomials at `x`. Shares are recombined
+//! by Lagrange interpolation of the polynomials at 0.
+//!
+//! Shares are encoded as:
+//!
+//! ```text
+//! stellar-share-<k>-<x>-<data>
+//! ```
+//!
+//! where `k` and `x` are decimal, and `data` is the lowercase unpadded base32
+//! encoding of the last 4 bytes of the ed25519 public key, the 32 byte value,
+//! and a 2 byte CRC-16/XMODEM checksum of `k ‖ x ‖ public key bytes ‖ value`
+//! in little endian, the same checksum used by strkeys. The public key bytes
+//! identify the key a share belongs to, so that shares of different keys are
+//! not mixed, and so that the recombined key can be checked.
+
+use core::{fmt::Display, str::FromStr};
+
+use heapless::{String, Vec};
+
+use crate::{crc::checksum, ed25519, error::DecodeError};
+
+/// The most shares a private key can be split into.
+pub const MAX_SHARES: usize = 255;
+
+/// Errors that can occur splitting or recombining a private key.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Error {
+    /// The threshold is less than 2 or greater than the number of shares.
+    InvalidThreshold,
+    /// Random bytes for the polynomials could not be generated.
+    Random,
+    /// Fewer shares were given than the threshold.
+    NotEnoughShares,
+    /// The shares have different thresholds or belong to different keys.
+    MismatchedShares,
+    /// More than one share has the same index.
+    DuplicateShare,
+    /// The recombined private key does not match the shares' public key.
+    KeyMismatch,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::InvalidThreshold => {
+                f.write_str("threshold must be at least 2 and at most the number of shares")
+            }
+            Error::Random => f.write_str("failed to generate random bytes"),
+            Error::NotEnoughShares => f.write_str("fewer shares than the threshold"),
+            Error::MismatchedShares => {
+                f.write_str("shares have different thresholds or belong to different keys")
+            }
+            Error::DuplicateShare => f.write_str("more than one share has the same index"),
+            Error::KeyMismatch => f.write_str("recombined private key does not match the shares"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Stores one share of a private key.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::SerializeDisplay, serde_with::DeserializeFromStr)
+)]
+pub struct Share {
+    /// The number of shares needed to recombine the private key.
+    pub threshold: u8,
+    /// The index of the share, from 1 to the number of shares.
+    pub index: u8,
+    /// The last 4 bytes of the ed25519 public key of the private key.
+    pub public_key_hint: [u8; 4],
+    /// The values of the polynomials at the index.
+    pub value: [u8; 32],
+}
+
+/// Multiplies in GF(2^8), without branching on the values.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0;
+    for _ in 0..8 {
+        p ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (0x1b & carry);
+        b >>= 1;
+    }
+    p
+}
+
+/// Inverts in GF(2^8), as `a^254`.
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1;
+    let mut base = a;
+    let mut exp = 254u8;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+fn public_key_hint(key: &ed25519::PrivateKey) -> [u8; 4] {
+    let public_key = key.public_key();
+    let mut hint = [0u8; 4];
+    hint.copy_from_slice(&public_key.0[28..]);
+    hint
+}
+
+/// Splits the private key into `shares` shares, any `threshold` of which
+/// recombine into the private key.
+///
+/// ### Errors
+///
+/// If the threshold is less than 2 or greater than the number of shares, or
+/// if random bytes cannot be generated.
+pub fn split(
+    key: &ed25519::PrivateKey,
+    threshold: u8,
+    shares: u8,
+) -> Result<Vec<Share, MAX_SHARES>, Error> {
+    if threshold < 2 || threshold > shares {
+        return Err(Error::InvalidThreshold);
+    }
+    let public_key_hint = public_key_hint(key);
+    let mut coefficients = [[0u8; 32]; MAX_SHARES];
+    let coefficients = &mut coefficients[..threshold as usize];
+    coefficients[0] = key.0;
+    for c in &mut coefficients[1..] {
+        getrandom::getrandom(c).map_err(|_| Error::Random)?;
+    }
+    let mut result = Vec::new();
+    for index in 1..=shares {
+        let mut value = [0u8; 32];
+        for (i, v) in value.iter_mut().enumerate() {
+            *v = coefficients
+                .iter()
+                .rev()
+                .fold(0, |y, c| gf_mul(y, index) ^ c[i]);
+        }
+        // Safety: push cannot fail because there are at most 255 shares.
+        result
+            .push(Share {
+                threshold,
+                index,
+                public_key_hint,
+                value,
+            })
+            .unwrap();
+    }
+    coefficients.fill([0; 32]);
+    Ok(result)
+}
+
+/// Recombines a private key from shares produced by [`split`].
+///
+/// Only the first `threshold` shares are used.
+///
+/// ### Errors
+///
+/// If there are fewer shares than the threshold, if the shares do not belong
+/// to the same split, or if the recombined key does not match the shares.
+pub fn combine(shares: &[Share]) -> Result<ed25519::PrivateKey, Error> {
+    let first = shares.first().ok_or(Error::NotEnoughShares)?;
+    if shares.len() < first.threshold as usize {
+        return Err(Error::NotEnoughShares);
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if share.threshold != first.threshold || share.public_key_hint != first.public_key_hint {
+            return Err(Error::MismatchedShares);
+        }
+        if shares[..i].iter().any(|s| s.index == share.index) {
+            return Err(Error::DuplicateShare);
+        }
+    }
+    let shares = &shares[..first.threshold as usize];
+    let mut key = [0u8; 32];
+    for (i, share) in shares.iter().enumerate() {
+        // The Lagrange basis polynomial for the share, evaluated at 0.
+        let mut basis = 1;
+        for (j, other) in shares.iter().enumerate() {
+            if i != j {
+                basis = gf_mul(
+                    basis,
+                    gf_mul(other.index, gf_inv(other.index ^ share.index)),
+                );
+            }
+        }
+        for (k, v) in key.iter_mut().zip(share.value) {
+            *k ^= gf_mul(basis, v);
+        }
+    }
+    let key = ed25519::PrivateKey(key);
+    if public_key_hint(&key) != first.public_key_hint {
+        return Err(Error::KeyMismatch);
+    }
+    Ok(key)
+}
+
+impl Share {
+    const PREFIX: &'static str = "stellar-share-";
+    // Data: 4 public key hint + 32 value + 2 checksum = 38
+    const DATA_LEN: usize = 4 + 32 + 2;
+    const DATA_ENCODED_LEN: usize = (Self::DATA_LEN * 8).div_ceil(5);
+    // "stellar-share-" + "255-255-" + data
+    pub(crate) const MAX_ENCODED_LEN: usize = 14 + 8 + Self::DATA_ENCODED_LEN;
+    const _ASSERTS: () = {
+        assert!(Self::DATA_ENCODED_LEN == 61);
+        assert!(Self::MAX_ENCODED_LEN == 83);
+    };
+
+    fn checksum(&self) -> [u8; 2] {
+        let mut body = [0u8; 2 + 4 + 32];
+        body[0] = self.threshold;
+        body[1] = self.index;
+        body[2..6].copy_from_slice(&self.public_key_hint);
+        body[6..].copy_from_slice(&self.value);
+        checksum(&body)
+    }
+
+    /// Returns the encoded share.
+    pub fn to_string(&self) -> String<{ Self::MAX_ENCODED_LEN }> {
+        let mut data = [0u8; Self::DATA_LEN];
+        data[..4].copy_from_slice(&self.public_key_hint);
+        data[4..36].copy_from_slice(&self.value);
+        data[36..].copy_from_slice(&self.checksum());
+        let mut encoded = [0u8; Self::DATA_ENCODED_LEN];
+        data_encoding::BASE32_NOPAD.encode_mut(&data, &mut encoded);
+        encoded.make_ascii_lowercase();
+
+        let mut s = String::new();
+        // Safety: unwrap cannot fail because the string has capacity for the
+        // longest thresholds and indexes, and base32 is valid UTF-8.
+        core::fmt::write(
+            &mut s,
+            format_args!(
+                "{}{}-{}-{}",
+                Self::PREFIX,
+                self.threshold,
+                self.index,
+                core::str::from_utf8(&encoded).unwrap()
+            ),
+        )
+        .unwrap();
+        s
+    }
+
+    /// Decodes an encoded share, checking its checksum.
+    ///
+    /// ### Errors
+    ///
+    /// If the string is not an encoded share, or the checksum does not match.
+    pub fn from_string(s: &str) -> Result<Self, DecodeError> {
+        let s = s.strip_prefix(Self::PREFIX).ok_or(DecodeError::Invalid)?;
+        let mut parts = s.splitn(3, '-');
+        let mut next_u8 = || -> Result<u8, DecodeError> {
+            let part = parts.next().ok_or(DecodeError::Invalid)?;
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(DecodeError::Invalid);
+            }
+            part.parse().map_err(|_| DecodeError::Invalid)
+        };
+        let threshold = next_u8()?;
+        let index = next_u8()?;
+        let encoded = parts.next().ok_or(DecodeError::Invalid)?;
+        if threshold < 2 || index == 0 || encoded.len() != Self::DATA_ENCODED_LEN {
+            return Err(DecodeError::Invalid);
+        }
+        let mut upper = [0u8; Self::DATA_ENCODED_LEN];
+        upper.copy_from_slice(encoded.as_bytes());
+        if upper.iter().any(u8::is_ascii_uppercase) {
+            return Err(DecodeError::Invalid);
+        }
+        upper.make_ascii_uppercase();
+        let mut data = [0u8; Self::DATA_LEN];
+        data_encoding::BASE32_NOPAD
+            .decode_mut(&upper, &mut data)
+            .map_err(|_| DecodeError::Invalid)?;
+
+        let mut share = Self {
+            threshold,
+            index,
+            public_key_hint: [0; 4],
+            value: [0; 32],
+        };
+        share.public_key_hint.copy_from_slice(&data[..4]);
+        share.value.copy_from_slice(&data[4..36]);
+        if share.checksum() != data[36..] {
+            return Err(DecodeError::Invalid);
+        }
+        Ok(share)
+    }
+}
+
+impl Display for Share {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(&self.to_string())
+    }
+}
+
+impl FromStr for Share {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Share::from_string(s)
+    }
+}
@@ -0,0 +1,131 @@
+use core::{fmt::Display, str::FromStr};
+
+use heapless::String;
+
+use crate::{ed25519, error::DecodeError, HashX, PreAuthTx, Strkey};
+
+/// Stores a key that can be an account signer: an ed25519 public key `G`, a
+/// pre-authorized transaction `T`, a hash-x `X`, or an ed25519 signed payload
+/// `P`.
+///
+/// Signer keys are displayed and parsed as strkeys. Parsing any other kind of
+/// strkey fails with [`DecodeError::UnexpectedKind`].
+///
+/// The variant order, and the field order within each variant, match the XDR
+/// `SignerKey` ordering that stellar-core uses to sort the signers of an
+/// account, so the derived [`Ord`] sorts and deduplicates signer lists the way
+/// the network does. Payloads compare lexicographically, so a payload sorts
+/// before any longer payload it is a prefix of.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::SerializeDisplay, serde_with::DeserializeFromStr)
+)]
+pub enum SignerKey {
+    Ed25519(ed25519::PublicKey),
+    PreAuthTx(PreAuthTx),
+    HashX(HashX),
+    Ed25519SignedPayload(ed25519::SignedPayload),
+}
+
+impl SignerKey {
+    // SignedPayload is the longest signer type.
+    const MAX_ENCODED_LEN: usize = ed25519::SignedPayload::MAX_ENCODED_LEN;
+    const _ASSERTS: () = {
+        assert!(Self::MAX_ENCODED_LEN >= ed25519::PublicKey::ENCODED_LEN);
+        assert!(Self::MAX_ENCODED_LEN >= PreAuthTx::ENCODED_LEN);
+        assert!(Self::MAX_ENCODED_LEN >= HashX::ENCODED_LEN);
+    };
+
+    pub fn to_string(&self) -> String<{ Self::MAX_ENCODED_LEN }> {
+        let mut s: String<{ Self::MAX_ENCODED_LEN }> = String::new();
+        match self {
+            Self::Ed25519(x) => s.push_str(x.to_string().as_str()).unwrap(),
+            Self::PreAuthTx(x) => s.push_str(x.to_string().as_str()).unwrap(),
+            Self::HashX(x) => s.push_str(x.to_string().as_str()).unwrap(),
+            Self::Ed25519SignedPayload(x) => s.push_str(x.to_string().as_str()).unwrap(),
+        }
+        s
+    }
+
+    pub fn from_string(s: &str) -> Result<Self, DecodeError> {
+        Self::from_slice(s.as_bytes())
+    }
+
+    /// Decodes a `G`, `T`, `X` or `P` strkey.
+    ///
+    /// ### Errors
+    ///
+    /// If the strkey is invalid, or with [`DecodeError::UnexpectedKind`] if it
+    /// is a valid strkey of another kind.
+    pub fn from_slice(s: &[u8]) -> Result<Self, DecodeError> {
+        Self::try_from(Strkey::from_slice(s)?)
+    }
+}
+
+impl Display for SignerKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(&self.to_string())
+    }
+}
+
+impl FromStr for SignerKey {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SignerKey::from_string(s)
+    }
+}
+
+impl TryFrom<Strkey> for SignerKey {
+    type Error = DecodeError;
+
+    fn try_from(strkey: Strkey) -> Result<Self, Self::Error> {
+        match strkey {
+            Strkey::PublicKeyEd25519(x) => Ok(Self::Ed25519(x)),
+            Strkey::PreAuthTx(x) => Ok(Self::PreAuthTx(x)),
+            Strkey::HashX(x) => Ok(Self::HashX(x)),
+            Strkey::SignedPayloadEd25519(x) => Ok(Self::Ed25519SignedPayload(x)),
+            Strkey::PrivateKeyEd25519(_)
+            | Strkey::MuxedAccountEd25519(_)
+            | Strkey::Contract(_)
+            | Strkey::LiquidityPool(_)
+            | Strkey::ClaimableBalance(_) => Err(DecodeError::UnexpectedKind),
+        }
+    }
+}
+
+impl From<SignerKey> for Strkey {
+    fn from(key: SignerKey) -> Self {
+        match key {
+            SignerKey::Ed25519(x) => Strkey::PublicKeyEd25519(x),
+            SignerKey::PreAuthTx(x) => Strkey::PreAuthTx(x),
+            SignerKey::HashX(x) => Strkey::HashX(x),
+            SignerKey::Ed25519SignedPayload(x) => Strkey::SignedPayloadEd25519(x),
+        }
+    }
+}
+
+#[cfg(feature = "serde-decoded")]
+mod signer_key_decoded_serde_impl {
+    use super::*;
+    use crate::decoded_json_format::Decoded;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    // Signer keys use the same JSON objects as the equivalent strkeys.
+
+    impl Serialize for Decoded<&SignerKey> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Decoded(&Strkey::from(self.0.clone())).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Decoded<SignerKey> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let Decoded(strkey) = Decoded::<Strkey>::deserialize(deserializer)?;
+            Ok(Decoded(
+                SignerKey::try_from(strkey).map_err(de::Error::custom)?,
+            ))
+        }
+    }
+}
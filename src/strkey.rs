@@ -3,10 +3,10 @@ use core::{
     str::FromStr,
 };
 
-use heapless::String as HeaplessString;
+use heapless::{String as HeaplessString, Vec};
 
 use crate::{
-    convert::{binary_len, decode, encode, encode_len},
+    convert::{binary_len, decode, decode_binary, encode, encode_binary, encode_len},
     ed25519,
     error::DecodeError,
     version,
@@ -33,7 +33,7 @@ impl Strkey {
     // SignedPayload is the longest strkey type.
     const MAX_PAYLOAD_LEN: usize = ed25519::SignedPayload::MAX_PAYLOAD_LEN;
     const MAX_BINARY_LEN: usize = binary_len(Self::MAX_PAYLOAD_LEN);
-    const MAX_ENCODED_LEN: usize = encode_len(Self::MAX_BINARY_LEN);
+    pub(crate) const MAX_ENCODED_LEN: usize = encode_len(Self::MAX_BINARY_LEN);
     const _ASSERTS: () = {
         assert!(Self::MAX_PAYLOAD_LEN == 100);
         assert!(Self::MAX_BINARY_LEN == 103);
@@ -92,27 +92,62 @@ impl Strkey {
 
     pub fn from_slice(s: &[u8]) -> Result<Self, DecodeError> {
         let (ver, payload) = decode::<{ Self::MAX_PAYLOAD_LEN }, { Self::MAX_BINARY_LEN }>(s)?;
+        Self::from_version_and_payload(ver, &payload)
+    }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded, e.g. 35 bytes for a `G`
+    /// strkey rather than 56 characters.
+    pub fn to_binary(&self) -> Vec<u8, { Self::MAX_BINARY_LEN }> {
+        // Safety: unwraps cannot fail because MAX_BINARY_LEN is at least the
+        // binary length of every type.
+        match self {
+            Self::PublicKeyEd25519(x) => Vec::from_slice(&x.to_binary()).unwrap(),
+            Self::PrivateKeyEd25519(x) => Vec::from_slice(&x.to_binary()).unwrap(),
+            Self::PreAuthTx(x) => Vec::from_slice(&x.to_binary()).unwrap(),
+            Self::HashX(x) => Vec::from_slice(&x.to_binary()).unwrap(),
+            Self::MuxedAccountEd25519(x) => Vec::from_slice(&x.to_binary()).unwrap(),
+            Self::SignedPayloadEd25519(x) => x.to_binary(),
+            Self::Contract(x) => Vec::from_slice(&x.to_binary()).unwrap(),
+            Self::LiquidityPool(x) => Vec::from_slice(&x.to_binary()).unwrap(),
+            Self::ClaimableBalance(x) => Vec::from_slice(&x.to_binary()).unwrap(),
+        }
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`, of any type.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is unknown, the checksum does not match, or the payload
+    /// is invalid for the version.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) =
+            decode_binary::<{ Self::MAX_PAYLOAD_LEN }, { Self::MAX_BINARY_LEN }>(b)?;
+        Self::from_version_and_payload(ver, &payload)
+    }
+
+    fn from_version_and_payload(ver: u8, payload: &[u8]) -> Result<Self, DecodeError> {
         match ver {
             version::PUBLIC_KEY_ED25519 => Ok(Self::PublicKeyEd25519(
-                ed25519::PublicKey::from_payload(&payload)?,
+                ed25519::PublicKey::from_payload(payload)?,
             )),
             version::PRIVATE_KEY_ED25519 => Ok(Self::PrivateKeyEd25519(
-                ed25519::PrivateKey::from_payload(&payload)?,
+                ed25519::PrivateKey::from_payload(payload)?,
             )),
-            version::PRE_AUTH_TX => Ok(Self::PreAuthTx(PreAuthTx::from_payload(&payload)?)),
-            version::HASH_X => Ok(Self::HashX(HashX::from_payload(&payload)?)),
+            version::PRE_AUTH_TX => Ok(Self::PreAuthTx(PreAuthTx::from_payload(payload)?)),
+            version::HASH_X => Ok(Self::HashX(HashX::from_payload(payload)?)),
             version::MUXED_ACCOUNT_ED25519 => Ok(Self::MuxedAccountEd25519(
-                ed25519::MuxedAccount::from_payload(&payload)?,
+                ed25519::MuxedAccount::from_payload(payload)?,
             )),
             version::SIGNED_PAYLOAD_ED25519 => Ok(Self::SignedPayloadEd25519(
-                ed25519::SignedPayload::from_payload(&payload)?,
+                ed25519::SignedPayload::from_payload(payload)?,
             )),
-            version::CONTRACT => Ok(Self::Contract(Contract::from_payload(&payload)?)),
+            version::CONTRACT => Ok(Self::Contract(Contract::from_payload(payload)?)),
             version::LIQUIDITY_POOL => {
-                Ok(Self::LiquidityPool(LiquidityPool::from_payload(&payload)?))
+                Ok(Self::LiquidityPool(LiquidityPool::from_payload(payload)?))
             }
             version::CLAIMABLE_BALANCE => Ok(Self::ClaimableBalance(
-                ClaimableBalance::from_payload(&payload)?,
+                ClaimableBalance::from_payload(payload)?,
             )),
             _ => Err(DecodeError::Invalid),
         }
@@ -121,7 +156,7 @@ impl Strkey {
 
 impl Display for Strkey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
@@ -307,11 +342,31 @@ impl PreAuthTx {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::BINARY_LEN }> {
+        encode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(version::PRE_AUTH_TX, &self.0)
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) = decode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(b)?;
+        match ver {
+            version::PRE_AUTH_TX => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
 }
 
 impl Display for PreAuthTx {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
@@ -403,11 +458,31 @@ impl HashX {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::BINARY_LEN }> {
+        encode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(version::HASH_X, &self.0)
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) = decode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(b)?;
+        match ver {
+            version::HASH_X => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
 }
 
 impl Display for HashX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
@@ -499,11 +574,31 @@ impl Contract {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::BINARY_LEN }> {
+        encode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(version::CONTRACT, &self.0)
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) = decode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(b)?;
+        match ver {
+            version::CONTRACT => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
 }
 
 impl Display for Contract {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
@@ -595,11 +690,34 @@ impl LiquidityPool {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::BINARY_LEN }> {
+        encode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(
+            version::LIQUIDITY_POOL,
+            &self.0,
+        )
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) = decode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(b)?;
+        match ver {
+            version::LIQUIDITY_POOL => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
 }
 
 impl Display for LiquidityPool {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
@@ -680,15 +798,19 @@ impl ClaimableBalance {
     };
 
     pub fn to_string(&self) -> HeaplessString<{ Self::ENCODED_LEN }> {
+        encode::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }, { Self::ENCODED_LEN }>(
+            version::CLAIMABLE_BALANCE,
+            &self.payload(),
+        )
+    }
+
+    fn payload(&self) -> [u8; Self::PAYLOAD_LEN] {
         match self {
             Self::V0(v0) => {
                 // First byte is zero for v0
                 let mut payload = [0; Self::PAYLOAD_LEN];
                 payload[1..].copy_from_slice(v0);
-                encode::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }, { Self::ENCODED_LEN }>(
-                    version::CLAIMABLE_BALANCE,
-                    &payload,
-                )
+                payload
             }
         }
     }
@@ -712,11 +834,34 @@ impl ClaimableBalance {
             _ => Err(DecodeError::Invalid),
         }
     }
+
+    /// Returns the binary strkey, `version ‖ payload ‖ checksum`, which is
+    /// the strkey before it is base32 encoded.
+    pub fn to_binary(&self) -> Vec<u8, { Self::BINARY_LEN }> {
+        encode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(
+            version::CLAIMABLE_BALANCE,
+            &self.payload(),
+        )
+    }
+
+    /// Decodes a binary strkey, `version ‖ payload ‖ checksum`.
+    ///
+    /// ### Errors
+    ///
+    /// If the version is not of this type, the checksum does not match, or
+    /// the payload is invalid.
+    pub fn from_binary(b: &[u8]) -> Result<Self, DecodeError> {
+        let (ver, payload) = decode_binary::<{ Self::PAYLOAD_LEN }, { Self::BINARY_LEN }>(b)?;
+        match ver {
+            version::CLAIMABLE_BALANCE => Self::from_payload(&payload),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
 }
 
 impl Display for ClaimableBalance {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
+        f.pad(&self.to_string())
     }
 }
 
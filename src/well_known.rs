@@ -0,0 +1,196 @@
+use core::fmt::Display;
+
+use heapless::Vec;
+
+use crate::{ed25519, ClaimableBalance, Contract, HashX, LiquidityPool, PreAuthTx, Strkey};
+
+/// A Stellar network, identified by its network passphrase.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Network {
+    Pubnet,
+    Testnet,
+    Futurenet,
+}
+
+impl Network {
+    /// Every network.
+    pub const ALL: [Network; 3] = [Network::Pubnet, Network::Testnet, Network::Futurenet];
+
+    /// Returns the network passphrase, which the network ID is the SHA-256
+    /// hash of.
+    pub fn passphrase(self) -> &'static str {
+        match self {
+            Self::Pubnet => "Public Global Stellar Network ; September 2015",
+            Self::Testnet => "Test SDF Network ; September 2015",
+            Self::Futurenet => "Test SDF Future Network ; October 2022",
+        }
+    }
+
+    /// Returns the name of the network, e.g. `pubnet`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Pubnet => "pubnet",
+            Self::Testnet => "testnet",
+            Self::Futurenet => "futurenet",
+        }
+    }
+
+    /// Returns the contract ID of the Stellar Asset Contract of the native
+    /// asset, XLM, on the network.
+    pub fn xlm_contract(self) -> Contract {
+        match self {
+            Self::Pubnet => XLM_PUBNET,
+            Self::Testnet => XLM_TESTNET,
+            Self::Futurenet => XLM_FUTURENET,
+        }
+    }
+}
+
+impl Display for Network {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// The Stellar Asset Contract of XLM on pubnet,
+/// `CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA`.
+pub const XLM_PUBNET: Contract = Contract([
+    0x25, 0xb4, 0xfc, 0xd8, 0x59, 0xae, 0xc2, 0xfa, 0x63, 0x48, 0x43, 0x8c, 0x48, 0x9b, 0x3c, 0x3c,
+    0x10, 0xc9, 0x8b, 0x6d, 0x21, 0xbe, 0x4f, 0xd3, 0xcb, 0x30, 0xcb, 0x68, 0x95, 0x3e, 0xf9, 0x77,
+]);
+
+/// The Stellar Asset Contract of XLM on testnet,
+/// `CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC`.
+pub const XLM_TESTNET: Contract = Contract([
+    0xd7, 0x92, 0x8b, 0x72, 0xc2, 0x70, 0x3c, 0xcf, 0xea, 0xf7, 0xeb, 0x9f, 0xf4, 0xef, 0x4d, 0x50,
+    0x4a, 0x55, 0xa8, 0xb9, 0x79, 0xfc, 0x9b, 0x45, 0x0e, 0xa2, 0xc8, 0x42, 0xb4, 0xd1, 0xce, 0x61,
+]);
+
+/// The Stellar Asset Contract of XLM on futurenet,
+/// `CB64D3G7SM2RTH6JSGG34DDTFTQ5CFDKVDZJZSODMCX4NJ2HV2KN7OHT`.
+pub const XLM_FUTURENET: Contract = Contract([
+    0x7d, 0xc1, 0xec, 0xdf, 0x93, 0x35, 0x19, 0x9f, 0xc9, 0x91, 0x8d, 0xbe, 0x0c, 0x73, 0x2c, 0xe1,
+    0xd1, 0x14, 0x6a, 0xa8, 0xf2, 0x9c, 0xc9, 0xc3, 0x60, 0xaf, 0xc6, 0xa7, 0x47, 0xae, 0x94, 0xdf,
+]);
+
+/// The all-zero account, which no one holds the private key of, and is used
+/// as a burn address.
+pub const ZERO_ACCOUNT: ed25519::PublicKey = ed25519::PublicKey([0; 32]);
+
+/// The all-zero muxed account, the zero account with memo ID 0.
+pub const ZERO_MUXED_ACCOUNT: ed25519::MuxedAccount = ed25519::MuxedAccount {
+    ed25519: [0; 32],
+    id: 0,
+};
+
+/// The all-zero signed payload signer, the zero account with an empty
+/// payload.
+pub const ZERO_SIGNED_PAYLOAD: ed25519::SignedPayload = ed25519::SignedPayload {
+    ed25519: [0; 32],
+    payload: Vec::new(),
+};
+
+/// The all-zero pre-authorized transaction hash.
+pub const ZERO_PRE_AUTH_TX: PreAuthTx = PreAuthTx([0; 32]);
+
+/// The all-zero hash-x signer.
+pub const ZERO_HASH_X: HashX = HashX([0; 32]);
+
+/// The all-zero contract ID.
+pub const ZERO_CONTRACT: Contract = Contract([0; 32]);
+
+/// The all-zero liquidity pool.
+pub const ZERO_LIQUIDITY_POOL: LiquidityPool = LiquidityPool([0; 32]);
+
+/// The all-zero claimable balance.
+pub const ZERO_CLAIMABLE_BALANCE: ClaimableBalance = ClaimableBalance::V0([0; 32]);
+
+/// A well-known strkey, with what it is and the network it is on, if it is
+/// particular to one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WellKnown {
+    pub name: &'static str,
+    pub network: Option<Network>,
+    pub strkey: Strkey,
+}
+
+impl Display for WellKnown {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.network {
+            Some(network) => write!(f, "{} on {network}", self.name),
+            None => f.write_str(self.name),
+        }
+    }
+}
+
+/// Every well-known strkey.
+pub static ALL: [WellKnown; 11] = [
+    WellKnown {
+        name: "XLM Stellar Asset Contract",
+        network: Some(Network::Pubnet),
+        strkey: Strkey::Contract(XLM_PUBNET),
+    },
+    WellKnown {
+        name: "XLM Stellar Asset Contract",
+        network: Some(Network::Testnet),
+        strkey: Strkey::Contract(XLM_TESTNET),
+    },
+    WellKnown {
+        name: "XLM Stellar Asset Contract",
+        network: Some(Network::Futurenet),
+        strkey: Strkey::Contract(XLM_FUTURENET),
+    },
+    WellKnown {
+        name: "zero account",
+        network: None,
+        strkey: Strkey::PublicKeyEd25519(ZERO_ACCOUNT),
+    },
+    WellKnown {
+        name: "zero muxed account",
+        network: None,
+        strkey: Strkey::MuxedAccountEd25519(ZERO_MUXED_ACCOUNT),
+    },
+    WellKnown {
+        name: "zero signed payload",
+        network: None,
+        strkey: Strkey::SignedPayloadEd25519(ZERO_SIGNED_PAYLOAD),
+    },
+    WellKnown {
+        name: "zero pre-authorized transaction",
+        network: None,
+        strkey: Strkey::PreAuthTx(ZERO_PRE_AUTH_TX),
+    },
+    WellKnown {
+        name: "zero hash-x",
+        network: None,
+        strkey: Strkey::HashX(ZERO_HASH_X),
+    },
+    WellKnown {
+        name: "zero contract",
+        network: None,
+        strkey: Strkey::Contract(ZERO_CONTRACT),
+    },
+    WellKnown {
+        name: "zero liquidity pool",
+        network: None,
+        strkey: Strkey::LiquidityPool(ZERO_LIQUIDITY_POOL),
+    },
+    WellKnown {
+        name: "zero claimable balance",
+        network: None,
+        strkey: Strkey::ClaimableBalance(ZERO_CLAIMABLE_BALANCE),
+    },
+];
+
+/// Returns the well-known strkey that is the strkey, if any.
+pub fn lookup(strkey: &Strkey) -> Option<&'static WellKnown> {
+    ALL.iter().find(|well_known| well_known.strkey == *strkey)
+}
+
+impl Strkey {
+    /// Returns what the strkey is if it is well-known, such as the XLM
+    /// Stellar Asset Contract of a network, or an all-zero burn address.
+    pub fn well_known(&self) -> Option<&'static WellKnown> {
+        lookup(self)
+    }
+}
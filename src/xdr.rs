@@ -0,0 +1,655 @@
+//! Conversions between the strkey types and the XDR fragments that carry the
+//! same keys, without depending on the generated XDR types.
+//!
+//! | Type | XDR |
+//! |------|-----|
+//! | [`Address`] | `SCAddress` |
+//! | [`AccountAddress`] | `MuxedAccount` |
+//! | [`SignerKey`] | `SignerKey` |
+//! | [`ed25519::PublicKey`] | `AccountID`/`PublicKey`, or `SCAddress` with `SC_ADDRESS_TYPE_ACCOUNT` |
+//! | [`ed25519::MuxedAccount`] | `MuxedAccount` with `KEY_TYPE_MUXED_ED25519`, or `SCAddress` with `SC_ADDRESS_TYPE_MUXED_ACCOUNT` |
+//! | [`Contract`] | `SCAddress` with `SC_ADDRESS_TYPE_CONTRACT` |
+//! | [`ClaimableBalance`] | `SCAddress` with `SC_ADDRESS_TYPE_CLAIMABLE_BALANCE` |
+//! | [`LiquidityPool`] | `SCAddress` with `SC_ADDRESS_TYPE_LIQUIDITY_POOL` |
+//! | [`PreAuthTx`] | `SignerKey` with `SIGNER_KEY_TYPE_PRE_AUTH_TX` |
+//! | [`HashX`] | `SignerKey` with `SIGNER_KEY_TYPE_HASH_X` |
+//! | [`ed25519::SignedPayload`] | `SignerKey` with `SIGNER_KEY_TYPE_ED25519_SIGNED_PAYLOAD` |
+//! | [`LedgerKey`] | `LedgerKey` |
+//! | [`ContractDataKey`] | `SCVal` |
+//!
+//! Decoding rejects unknown discriminants, trailing bytes, and non-zero
+//! padding. Base64 is the standard padded alphabet used by stellar-core and
+//! Horizon.
+
+use data_encoding::BASE64;
+use heapless::{String, Vec};
+
+use crate::{
+    ed25519, error::DecodeError, AccountAddress, Address, ClaimableBalance, Contract,
+    ContractDataDurability, ContractDataKey, HashX, LedgerKey, LiquidityPool, PreAuthTx, SignerKey,
+};
+
+// PublicKeyType
+const PUBLIC_KEY_TYPE_ED25519: u32 = 0;
+
+// CryptoKeyType
+const KEY_TYPE_MUXED_ED25519: u32 = 0x100;
+
+// SCAddressType
+const SC_ADDRESS_TYPE_ACCOUNT: u32 = 0;
+const SC_ADDRESS_TYPE_CONTRACT: u32 = 1;
+const SC_ADDRESS_TYPE_MUXED_ACCOUNT: u32 = 2;
+const SC_ADDRESS_TYPE_CLAIMABLE_BALANCE: u32 = 3;
+const SC_ADDRESS_TYPE_LIQUIDITY_POOL: u32 = 4;
+
+// ClaimableBalanceIDType
+const CLAIMABLE_BALANCE_ID_TYPE_V0: u32 = 0;
+
+// SignerKeyType
+const SIGNER_KEY_TYPE_ED25519: u32 = 0;
+const SIGNER_KEY_TYPE_PRE_AUTH_TX: u32 = 1;
+const SIGNER_KEY_TYPE_HASH_X: u32 = 2;
+const SIGNER_KEY_TYPE_ED25519_SIGNED_PAYLOAD: u32 = 3;
+
+// LedgerEntryType
+const ACCOUNT: u32 = 0;
+const CLAIMABLE_BALANCE: u32 = 4;
+const LIQUIDITY_POOL: u32 = 5;
+const CONTRACT_DATA: u32 = 6;
+
+// SCValType
+const SCV_LEDGER_KEY_CONTRACT_INSTANCE: u32 = 20;
+
+// ContractDataDurability
+const TEMPORARY: u32 = 0;
+const PERSISTENT: u32 = 1;
+
+/// Returns the length of the padded base64 encoding of `len` bytes.
+const fn base64_len(len: usize) -> usize {
+    len.div_ceil(3) * 4
+}
+
+/// Encodes the XDR as padded base64.
+fn encode_base64<const N: usize>(xdr: &[u8]) -> String<N> {
+    let mut buf = [0u8; N];
+    let len = BASE64.encode_len(xdr.len());
+    BASE64.encode_mut(xdr, &mut buf[..len]);
+    let mut s = String::new();
+    // Safety: unwraps cannot fail because base64 is ASCII and callers size N
+    // with base64_len for the longest XDR they encode.
+    s.push_str(core::str::from_utf8(&buf[..len]).unwrap())
+        .unwrap();
+    s
+}
+
+/// Decodes padded base64 into at most `N` bytes of XDR.
+fn decode_base64<const N: usize>(s: &str) -> Result<Vec<u8, N>, DecodeError> {
+    // The buffer fits the decoding of the longest base64 accepted by any type.
+    const MAX_XDR_LEN: usize = if ContractDataKey::MAX_XDR_LEN > ed25519::SignedPayload::MAX_XDR_LEN
+    {
+        ContractDataKey::MAX_XDR_LEN
+    } else {
+        ed25519::SignedPayload::MAX_XDR_LEN
+    };
+    const BUF_LEN: usize = base64_len(MAX_XDR_LEN) / 4 * 3;
+    if s.len() > base64_len(N) {
+        return Err(DecodeError::Invalid);
+    }
+    let len = BASE64
+        .decode_len(s.len())
+        .map_err(|_| DecodeError::Invalid)?;
+    let mut buf = [0u8; BUF_LEN];
+    let buf = buf.get_mut(..len).ok_or(DecodeError::Invalid)?;
+    let len = BASE64
+        .decode_mut(s.as_bytes(), buf)
+        .map_err(|_| DecodeError::Invalid)?;
+    Vec::from_slice(&buf[..len]).map_err(|()| DecodeError::Invalid)
+}
+
+/// Splits a 4 byte discriminant from the front of the XDR.
+fn split_discriminant(xdr: &[u8]) -> Result<(u32, &[u8]), DecodeError> {
+    let (discriminant, rest) = xdr.split_first_chunk::<4>().ok_or(DecodeError::Invalid)?;
+    Ok((u32::from_be_bytes(*discriminant), rest))
+}
+
+/// Decodes XDR that is the discriminant followed by exactly 32 bytes.
+fn decode_32(discriminant: u32, xdr: &[u8]) -> Result<[u8; 32], DecodeError> {
+    match split_discriminant(xdr)? {
+        (d, rest) if d == discriminant => rest.try_into().map_err(|_| DecodeError::Invalid),
+        _ => Err(DecodeError::Invalid),
+    }
+}
+
+/// Encodes the discriminant followed by the 32 bytes.
+fn encode_32(discriminant: u32, bytes: &[u8; 32]) -> [u8; 36] {
+    let mut xdr = [0u8; 36];
+    xdr[..4].copy_from_slice(&discriminant.to_be_bytes());
+    xdr[4..].copy_from_slice(bytes);
+    xdr
+}
+
+impl ed25519::PublicKey {
+    /// Returns the XDR `AccountID`, which is also the XDR `PublicKey` and the
+    /// ed25519 `SignerKey`.
+    pub fn to_xdr(&self) -> [u8; 36] {
+        encode_32(PUBLIC_KEY_TYPE_ED25519, &self.0)
+    }
+
+    /// Decodes an XDR `AccountID`, `PublicKey` or ed25519 `SignerKey`.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly an ed25519 public key.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self(decode_32(PUBLIC_KEY_TYPE_ED25519, xdr)?))
+    }
+
+    /// Returns the base64 XDR `AccountID`.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(36) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `AccountID`.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<36>(s)?)
+    }
+
+    /// Returns the XDR `SCAddress` of the account.
+    pub fn to_sc_address_xdr(&self) -> [u8; 40] {
+        let mut xdr = [0u8; 40];
+        xdr[..4].copy_from_slice(&SC_ADDRESS_TYPE_ACCOUNT.to_be_bytes());
+        xdr[4..].copy_from_slice(&self.to_xdr());
+        xdr
+    }
+
+    /// Decodes an XDR `SCAddress` of an account.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly an `SCAddress` of an account.
+    pub fn from_sc_address_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        match split_discriminant(xdr)? {
+            (SC_ADDRESS_TYPE_ACCOUNT, rest) => Self::from_xdr(rest),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Returns the base64 XDR `SCAddress` of the account.
+    pub fn to_sc_address_xdr_base64(&self) -> String<{ base64_len(40) }> {
+        encode_base64(&self.to_sc_address_xdr())
+    }
+
+    /// Decodes a base64 XDR `SCAddress` of an account.
+    pub fn from_sc_address_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_sc_address_xdr(&decode_base64::<40>(s)?)
+    }
+}
+
+impl ed25519::MuxedAccount {
+    /// Writes the id and ed25519 key that follow the discriminant in both the
+    /// XDR `MuxedAccount` and the XDR `SCAddress`.
+    fn encode_with(&self, discriminant: u32) -> [u8; 44] {
+        let mut xdr = [0u8; 44];
+        xdr[..4].copy_from_slice(&discriminant.to_be_bytes());
+        xdr[4..12].copy_from_slice(&self.id.to_be_bytes());
+        xdr[12..].copy_from_slice(&self.ed25519);
+        xdr
+    }
+
+    fn decode_with(discriminant: u32, xdr: &[u8]) -> Result<Self, DecodeError> {
+        let (d, rest) = split_discriminant(xdr)?;
+        if d != discriminant || rest.len() != 8 + 32 {
+            return Err(DecodeError::Invalid);
+        }
+        let (id, ed25519) = rest.split_at(8);
+        Ok(Self {
+            ed25519: ed25519.try_into().map_err(|_| DecodeError::Invalid)?,
+            id: u64::from_be_bytes(id.try_into().map_err(|_| DecodeError::Invalid)?),
+        })
+    }
+
+    /// Returns the XDR `MuxedAccount`, with the `KEY_TYPE_MUXED_ED25519`
+    /// discriminant.
+    pub fn to_xdr(&self) -> [u8; 44] {
+        self.encode_with(KEY_TYPE_MUXED_ED25519)
+    }
+
+    /// Decodes an XDR `MuxedAccount` with the `KEY_TYPE_MUXED_ED25519`
+    /// discriminant.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly a muxed ed25519 account. An XDR
+    /// `MuxedAccount` holding a plain ed25519 key is rejected, use
+    /// [`ed25519::PublicKey::from_xdr`] for those.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(KEY_TYPE_MUXED_ED25519, xdr)
+    }
+
+    /// Returns the base64 XDR `MuxedAccount`.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(44) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `MuxedAccount`.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<44>(s)?)
+    }
+
+    /// Returns the XDR `SCAddress` of the muxed account.
+    pub fn to_sc_address_xdr(&self) -> [u8; 44] {
+        self.encode_with(SC_ADDRESS_TYPE_MUXED_ACCOUNT)
+    }
+
+    /// Decodes an XDR `SCAddress` of a muxed account.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly an `SCAddress` of a muxed account.
+    pub fn from_sc_address_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode_with(SC_ADDRESS_TYPE_MUXED_ACCOUNT, xdr)
+    }
+
+    /// Returns the base64 XDR `SCAddress` of the muxed account.
+    pub fn to_sc_address_xdr_base64(&self) -> String<{ base64_len(44) }> {
+        encode_base64(&self.to_sc_address_xdr())
+    }
+
+    /// Decodes a base64 XDR `SCAddress` of a muxed account.
+    pub fn from_sc_address_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_sc_address_xdr(&decode_base64::<44>(s)?)
+    }
+}
+
+impl ed25519::SignedPayload {
+    // XDR: 4 type + 32 ed25519 + 4 len + 64 payload = 104
+    const MAX_XDR_LEN: usize = 4 + Self::MAX_PAYLOAD_LEN;
+
+    /// Returns the XDR `SignerKey` of the signed payload signer.
+    pub fn to_xdr(&self) -> Vec<u8, { Self::MAX_XDR_LEN }> {
+        // The XDR after the discriminant is the strkey payload, the ed25519 key
+        // followed by the payload as variable length opaque data.
+        let len = self.payload.len();
+        let padding = (4 - len % 4) % 4;
+        // Safety: unwraps cannot fail because MAX_XDR_LEN is the length of the
+        // XDR of the longest payload.
+        let mut xdr = Vec::new();
+        xdr.extend_from_slice(&SIGNER_KEY_TYPE_ED25519_SIGNED_PAYLOAD.to_be_bytes())
+            .unwrap();
+        xdr.extend_from_slice(&self.ed25519).unwrap();
+        xdr.extend_from_slice(&(len as u32).to_be_bytes()).unwrap();
+        xdr.extend_from_slice(&self.payload).unwrap();
+        xdr.extend_from_slice(&[0; 3][..padding]).unwrap();
+        xdr
+    }
+
+    /// Decodes an XDR `SignerKey` of a signed payload signer.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly a signed payload signer, or if the payload is
+    /// empty or longer than 64 bytes.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        match split_discriminant(xdr)? {
+            (SIGNER_KEY_TYPE_ED25519_SIGNED_PAYLOAD, rest) => Self::from_payload(rest),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Returns the base64 XDR `SignerKey` of the signed payload signer.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(Self::MAX_XDR_LEN) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `SignerKey` of a signed payload signer.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<{ Self::MAX_XDR_LEN }>(s)?)
+    }
+}
+
+impl Contract {
+    /// Returns the XDR `SCAddress` of the contract.
+    pub fn to_xdr(&self) -> [u8; 36] {
+        encode_32(SC_ADDRESS_TYPE_CONTRACT, &self.0)
+    }
+
+    /// Decodes an XDR `SCAddress` of a contract.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly an `SCAddress` of a contract.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self(decode_32(SC_ADDRESS_TYPE_CONTRACT, xdr)?))
+    }
+
+    /// Returns the base64 XDR `SCAddress` of the contract.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(36) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `SCAddress` of a contract.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<36>(s)?)
+    }
+}
+
+impl ClaimableBalance {
+    /// Returns the XDR `SCAddress` of the claimable balance.
+    pub fn to_xdr(&self) -> [u8; 40] {
+        let Self::V0(hash) = self;
+        let mut xdr = [0u8; 40];
+        xdr[..4].copy_from_slice(&SC_ADDRESS_TYPE_CLAIMABLE_BALANCE.to_be_bytes());
+        xdr[4..].copy_from_slice(&encode_32(CLAIMABLE_BALANCE_ID_TYPE_V0, hash));
+        xdr
+    }
+
+    /// Decodes an XDR `SCAddress` of a claimable balance.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly an `SCAddress` of a claimable balance with a
+    /// version 0 ID.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        match split_discriminant(xdr)? {
+            (SC_ADDRESS_TYPE_CLAIMABLE_BALANCE, rest) => {
+                Ok(Self::V0(decode_32(CLAIMABLE_BALANCE_ID_TYPE_V0, rest)?))
+            }
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Returns the base64 XDR `SCAddress` of the claimable balance.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(40) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `SCAddress` of a claimable balance.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<40>(s)?)
+    }
+}
+
+impl LiquidityPool {
+    /// Returns the XDR `SCAddress` of the liquidity pool.
+    pub fn to_xdr(&self) -> [u8; 36] {
+        encode_32(SC_ADDRESS_TYPE_LIQUIDITY_POOL, &self.0)
+    }
+
+    /// Decodes an XDR `SCAddress` of a liquidity pool.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly an `SCAddress` of a liquidity pool.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self(decode_32(SC_ADDRESS_TYPE_LIQUIDITY_POOL, xdr)?))
+    }
+
+    /// Returns the base64 XDR `SCAddress` of the liquidity pool.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(36) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `SCAddress` of a liquidity pool.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<36>(s)?)
+    }
+}
+
+impl PreAuthTx {
+    /// Returns the XDR `SignerKey` of the pre-authorized transaction.
+    pub fn to_xdr(&self) -> [u8; 36] {
+        encode_32(SIGNER_KEY_TYPE_PRE_AUTH_TX, &self.0)
+    }
+
+    /// Decodes an XDR `SignerKey` of a pre-authorized transaction.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly a pre-authorized transaction signer.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self(decode_32(SIGNER_KEY_TYPE_PRE_AUTH_TX, xdr)?))
+    }
+
+    /// Returns the base64 XDR `SignerKey` of the pre-authorized transaction.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(36) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `SignerKey` of a pre-authorized transaction.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<36>(s)?)
+    }
+}
+
+impl HashX {
+    /// Returns the XDR `SignerKey` of the hash-x signer.
+    pub fn to_xdr(&self) -> [u8; 36] {
+        encode_32(SIGNER_KEY_TYPE_HASH_X, &self.0)
+    }
+
+    /// Decodes an XDR `SignerKey` of a hash-x signer.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly a hash-x signer.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self(decode_32(SIGNER_KEY_TYPE_HASH_X, xdr)?))
+    }
+
+    /// Returns the base64 XDR `SignerKey` of the hash-x signer.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(36) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `SignerKey` of a hash-x signer.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<36>(s)?)
+    }
+}
+
+impl Address {
+    // XDR: 4 type + 8 id + 32 ed25519 = 44
+    const MAX_XDR_LEN: usize = 44;
+
+    /// Returns the XDR `SCAddress`.
+    pub fn to_xdr(&self) -> Vec<u8, { Self::MAX_XDR_LEN }> {
+        // Safety: unwraps cannot fail because MAX_XDR_LEN is the length of the
+        // XDR of the longest address.
+        match self {
+            Self::Account(x) => Vec::from_slice(&x.to_sc_address_xdr()).unwrap(),
+            Self::MuxedAccount(x) => Vec::from_slice(&x.to_sc_address_xdr()).unwrap(),
+            Self::Contract(x) => Vec::from_slice(&x.to_xdr()).unwrap(),
+            Self::ClaimableBalance(x) => Vec::from_slice(&x.to_xdr()).unwrap(),
+            Self::LiquidityPool(x) => Vec::from_slice(&x.to_xdr()).unwrap(),
+        }
+    }
+
+    /// Decodes an XDR `SCAddress`.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly an `SCAddress`.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        match split_discriminant(xdr)?.0 {
+            SC_ADDRESS_TYPE_ACCOUNT => {
+                Ok(Self::Account(ed25519::PublicKey::from_sc_address_xdr(xdr)?))
+            }
+            SC_ADDRESS_TYPE_CONTRACT => Ok(Self::Contract(Contract::from_xdr(xdr)?)),
+            SC_ADDRESS_TYPE_MUXED_ACCOUNT => Ok(Self::MuxedAccount(
+                ed25519::MuxedAccount::from_sc_address_xdr(xdr)?,
+            )),
+            SC_ADDRESS_TYPE_CLAIMABLE_BALANCE => {
+                Ok(Self::ClaimableBalance(ClaimableBalance::from_xdr(xdr)?))
+            }
+            SC_ADDRESS_TYPE_LIQUIDITY_POOL => {
+                Ok(Self::LiquidityPool(LiquidityPool::from_xdr(xdr)?))
+            }
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Returns the base64 XDR `SCAddress`.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(Self::MAX_XDR_LEN) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `SCAddress`.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<{ Self::MAX_XDR_LEN }>(s)?)
+    }
+}
+
+impl AccountAddress {
+    // XDR: 4 type + 8 id + 32 ed25519 = 44
+    const MAX_XDR_LEN: usize = 44;
+
+    /// Returns the XDR `MuxedAccount`, with the `KEY_TYPE_ED25519`
+    /// discriminant for an account, and the `KEY_TYPE_MUXED_ED25519`
+    /// discriminant for a muxed account.
+    pub fn to_xdr(&self) -> Vec<u8, { Self::MAX_XDR_LEN }> {
+        // Safety: unwraps cannot fail because MAX_XDR_LEN is the length of the
+        // XDR of a muxed account.
+        match self {
+            Self::Account(x) => Vec::from_slice(&x.to_xdr()).unwrap(),
+            Self::MuxedAccount(x) => Vec::from_slice(&x.to_xdr()).unwrap(),
+        }
+    }
+
+    /// Decodes an XDR `MuxedAccount` of either type.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly a `MuxedAccount`.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        match split_discriminant(xdr)?.0 {
+            PUBLIC_KEY_TYPE_ED25519 => Ok(Self::Account(ed25519::PublicKey::from_xdr(xdr)?)),
+            KEY_TYPE_MUXED_ED25519 => Ok(Self::MuxedAccount(ed25519::MuxedAccount::from_xdr(xdr)?)),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Returns the base64 XDR `MuxedAccount`.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(Self::MAX_XDR_LEN) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `MuxedAccount`.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<{ Self::MAX_XDR_LEN }>(s)?)
+    }
+}
+
+impl SignerKey {
+    const MAX_XDR_LEN: usize = ed25519::SignedPayload::MAX_XDR_LEN;
+
+    /// Returns the XDR `SignerKey`.
+    pub fn to_xdr(&self) -> Vec<u8, { Self::MAX_XDR_LEN }> {
+        // Safety: unwraps cannot fail because MAX_XDR_LEN is the length of the
+        // XDR of the longest signer.
+        match self {
+            Self::Ed25519(x) => Vec::from_slice(&x.to_xdr()).unwrap(),
+            Self::PreAuthTx(x) => Vec::from_slice(&x.to_xdr()).unwrap(),
+            Self::HashX(x) => Vec::from_slice(&x.to_xdr()).unwrap(),
+            Self::Ed25519SignedPayload(x) => x.to_xdr(),
+        }
+    }
+
+    /// Decodes an XDR `SignerKey`.
+    ///
+    /// ### Errors
+    ///
+    /// If the XDR is not exactly a `SignerKey`.
+    pub fn from_xdr(xdr: &[u8]) -> Result<Self, DecodeError> {
+        match split_discriminant(xdr)?.0 {
+            SIGNER_KEY_TYPE_ED25519 => Ok(Self::Ed25519(ed25519::PublicKey::from_xdr(xdr)?)),
+            SIGNER_KEY_TYPE_PRE_AUTH_TX => Ok(Self::PreAuthTx(PreAuthTx::from_xdr(xdr)?)),
+            SIGNER_KEY_TYPE_HASH_X => Ok(Self::HashX(HashX::from_xdr(xdr)?)),
+            SIGNER_KEY_TYPE_ED25519_SIGNED_PAYLOAD => Ok(Self::Ed25519SignedPayload(
+                ed25519::SignedPayload::from_xdr(xdr)?,
+            )),
+            _ => Err(DecodeError::Invalid),
+        }
+    }
+
+    /// Returns the base64 XDR `SignerKey`.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(Self::MAX_XDR_LEN) }> {
+        encode_base64(&self.to_xdr())
+    }
+
+    /// Decodes a base64 XDR `SignerKey`.
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<{ Self::MAX_XDR_LEN }>(s)?)
+    }
+}
+
+impl ContractDataKey {
+    /// Returns the XDR `SCVal` of the key.
+    pub fn to_xdr(&self) -> Vec<u8, { Self::MAX_XDR_LEN }> {
+        match self {
+            // Safety: unwrap cannot fail because MAX_XDR_LEN is more than 4.
+            Self::Instance => {
+                Vec::from_slice(&SCV_LEDGER_KEY_CONTRACT_INSTANCE.to_be_bytes()).unwrap()
+            }
+            Self::Data(xdr) => xdr.clone(),
+        }
+    }
+
+    /// Decodes a base64 XDR `SCVal` as a data key.
+    ///
+    /// ### Errors
+    ///
+    /// As for [`ContractDataKey::from_xdr`].
+    pub fn from_xdr_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::from_xdr(&decode_base64::<{ Self::MAX_XDR_LEN }>(s)?)
+    }
+}
+
+impl LedgerKey {
+    // XDR: 4 type + 36 contract + key + 4 durability
+    const MAX_XDR_LEN: usize = 4 + 36 + ContractDataKey::MAX_XDR_LEN + 4;
+
+    /// Returns the XDR `LedgerKey`.
+    pub fn to_xdr(&self) -> Vec<u8, { Self::MAX_XDR_LEN }> {
+        let mut xdr = Vec::new();
+        // Safety: unwraps cannot fail because MAX_XDR_LEN is the length of the
+        // XDR of the longest ledger key.
+        match self {
+            Self::Account(account) => {
+                xdr.extend_from_slice(&ACCOUNT.to_be_bytes()).unwrap();
+                xdr.extend_from_slice(&account.to_xdr()).unwrap();
+            }
+            Self::ClaimableBalance(ClaimableBalance::V0(hash)) => {
+                xdr.extend_from_slice(&CLAIMABLE_BALANCE.to_be_bytes())
+                    .unwrap();
+                xdr.extend_from_slice(&encode_32(CLAIMABLE_BALANCE_ID_TYPE_V0, hash))
+                    .unwrap();
+            }
+            Self::LiquidityPool(pool) => {
+                xdr.extend_from_slice(&LIQUIDITY_POOL.to_be_bytes())
+                    .unwrap();
+                xdr.extend_from_slice(&pool.0).unwrap();
+            }
+            Self::ContractData {
+                contract,
+                key,
+                durability,
+            } => {
+                let durability = match durability {
+                    ContractDataDurability::Temporary => TEMPORARY,
+                    ContractDataDurability::Persistent => PERSISTENT,
+                };
+                xdr.extend_from_slice(&CONTRACT_DATA.to_be_bytes()).unwrap();
+                xdr.extend_from_slice(&contract.to_xdr()).unwrap();
+                xdr.extend_from_slice(&key.to_xdr()).unwrap();
+                xdr.extend_from_slice(&durability.to_be_bytes()).unwrap();
+            }
+        }
+        xdr
+    }
+
+    /// Returns the base64 XDR `LedgerKey`, as passed to RPC
+    /// `getLedgerEntries`.
+    pub fn to_xdr_base64(&self) -> String<{ base64_len(Self::MAX_XDR_LEN) }> {
+        encode_base64(&self.to_xdr())
+    }
+}
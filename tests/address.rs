@@ -0,0 +1,208 @@
+use stellar_strkey::*;
+
+const ACCOUNT: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const MUXED: &str = "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAAAAAAPCICBKU";
+const CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+const CLAIMABLE_BALANCE: &str = "BAADMPVKHBTYIH522D2O3CGHPHSP4ZXFNISHBXEYYDWJYBZ5AXD3CA3GDE";
+const LIQUIDITY_POOL: &str = "LA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZ5J";
+
+#[test]
+fn test_address() {
+    let cases = [
+        (ACCOUNT, "Account"),
+        (MUXED, "MuxedAccount"),
+        (CONTRACT, "Contract"),
+        (CLAIMABLE_BALANCE, "ClaimableBalance"),
+        (LIQUIDITY_POOL, "LiquidityPool"),
+    ];
+    for (s, variant) in cases {
+        let address = Address::from_string(s).unwrap();
+        assert!(format!("{address:?}").starts_with(variant), "{s}");
+        assert_eq!(address.to_string().as_str(), s);
+        assert_eq!(format!("{address}"), s);
+        assert_eq!(s.parse(), Ok(address));
+
+        let strkey = Strkey::from_string(s).unwrap();
+        assert_eq!(Address::try_from(strkey.clone()), Ok(address));
+        assert_eq!(Strkey::from(address), strkey);
+    }
+}
+
+#[test]
+fn test_address_unexpected_kind() {
+    for s in [
+        "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR",
+        "TA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZAM",
+        "XA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQH5FV",
+        "PA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUAAAAAQACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB6IBZGM",
+    ] {
+        assert_eq!(
+            Address::from_string(s),
+            Err(DecodeError::UnexpectedKind),
+            "{s}"
+        );
+        assert_eq!(
+            Address::try_from(Strkey::from_string(s).unwrap()),
+            Err(DecodeError::UnexpectedKind),
+            "{s}"
+        );
+    }
+    for s in ["", "G", &ACCOUNT[1..], &format!("{ACCOUNT}A")] {
+        assert_eq!(Address::from_string(s), Err(DecodeError::Invalid), "{s}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let address = Address::from_string(CONTRACT).unwrap();
+    serde_test::assert_tokens(&address, &[serde_test::Token::Str(CONTRACT)]);
+}
+
+#[cfg(feature = "serde-decoded")]
+#[test]
+fn test_decoded() {
+    for s in [ACCOUNT, MUXED, CONTRACT, CLAIMABLE_BALANCE, LIQUIDITY_POOL] {
+        let address = Address::from_string(s).unwrap();
+        let json = serde_json::to_string(&Decoded(&address)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::to_string(&Decoded(&Strkey::from_string(s).unwrap())).unwrap()
+        );
+        let Decoded(decoded): Decoded<Address> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, address);
+    }
+
+    let json = r#"{"hash_x":"363eaa3867841fbad0f4ed88c779e4fe66e56a2470dc98c0ec9c073d05c7b103"}"#;
+    assert!(serde_json::from_str::<Decoded<Address>>(json).is_err());
+}
+
+#[cfg(feature = "xdr")]
+#[test]
+fn test_xdr() {
+    // Vectors generated with the stellar-xdr crate.
+    let cases = [
+        (
+            ACCOUNT,
+            "AAAAAAAAAAA2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAw==",
+        ),
+        (
+            MUXED,
+            "AAAAAgAAAAAAAeJANj6qOGeEH7rQ9O2Ix3nk/mblaiRw3JjA7JwHPQXHsQM=",
+        ),
+        (CONTRACT, "AAAAATY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED"),
+        (
+            CLAIMABLE_BALANCE,
+            "AAAAAwAAAAA2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAw==",
+        ),
+        (
+            LIQUIDITY_POOL,
+            "AAAABDY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED",
+        ),
+    ];
+    for (s, b64) in cases {
+        let address = Address::from_string(s).unwrap();
+        assert_eq!(address.to_xdr_base64().as_str(), b64, "{s}");
+        assert_eq!(Address::from_xdr_base64(b64), Ok(address), "{s}");
+        assert_eq!(Address::from_xdr(&address.to_xdr()), Ok(address), "{s}");
+    }
+    assert_eq!(Address::from_xdr(&[0, 0, 0, 5]), Err(DecodeError::Invalid));
+}
+
+#[test]
+fn test_account_address() {
+    let account = AccountAddress::from_string(ACCOUNT).unwrap();
+    let muxed = AccountAddress::from_string(MUXED).unwrap();
+    let key = ed25519::PublicKey::from_string(ACCOUNT).unwrap();
+
+    assert_eq!(account, AccountAddress::Account(key));
+    assert_eq!(account.base_account(), key);
+    assert_eq!(account.mux_id(), None);
+    assert_eq!(muxed, AccountAddress::MuxedAccount(key.muxed(123456)));
+    assert_eq!(muxed.base_account(), key);
+    assert_eq!(muxed.mux_id(), Some(123456));
+
+    assert_ne!(account, muxed);
+    assert!(account.is_same_account(&muxed));
+    assert!(muxed.is_same_account(&key.muxed(1).into()));
+    assert!(!muxed.is_same_account(&ed25519::PublicKey([0; 32]).into()));
+    assert_eq!(muxed.normalize(), account);
+    assert_eq!(muxed.normalize().to_string().as_str(), ACCOUNT);
+    assert_eq!(account.normalize(), account);
+
+    for (address, s) in [(account, ACCOUNT), (muxed, MUXED)] {
+        assert_eq!(address.to_string().as_str(), s);
+        assert_eq!(format!("{address}"), s);
+        assert_eq!(s.parse(), Ok(address));
+        let strkey = Strkey::from_string(s).unwrap();
+        assert_eq!(AccountAddress::try_from(strkey.clone()), Ok(address));
+        assert_eq!(Strkey::from(address), strkey);
+        assert_eq!(
+            AccountAddress::try_from(Address::from(address)),
+            Ok(address)
+        );
+    }
+}
+
+#[test]
+fn test_account_address_unexpected_kind() {
+    for s in [
+        CONTRACT,
+        CLAIMABLE_BALANCE,
+        LIQUIDITY_POOL,
+        "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR",
+        "TA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZAM",
+    ] {
+        assert_eq!(
+            AccountAddress::from_string(s),
+            Err(DecodeError::UnexpectedKind),
+            "{s}"
+        );
+    }
+    assert_eq!(AccountAddress::from_string(""), Err(DecodeError::Invalid));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_account_address_serde() {
+    let address = AccountAddress::from_string(MUXED).unwrap();
+    serde_test::assert_tokens(&address, &[serde_test::Token::Str(MUXED)]);
+}
+
+#[cfg(feature = "serde-decoded")]
+#[test]
+fn test_account_address_decoded() {
+    let address = AccountAddress::from_string(MUXED).unwrap();
+    let json = serde_json::to_string(&Decoded(&address)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"muxed_account_ed25519":{"ed25519":"363eaa3867841fbad0f4ed88c779e4fe66e56a2470dc98c0ec9c073d05c7b103","id":123456}}"#
+    );
+    let Decoded(decoded): Decoded<AccountAddress> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, address);
+
+    let json = serde_json::to_string(&Decoded(&Strkey::from_string(CONTRACT).unwrap())).unwrap();
+    assert!(serde_json::from_str::<Decoded<AccountAddress>>(&json).is_err());
+}
+
+#[cfg(feature = "xdr")]
+#[test]
+fn test_account_address_xdr() {
+    // Vectors generated with the stellar-xdr crate.
+    let cases = [
+        (ACCOUNT, "AAAAADY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED"),
+        (
+            MUXED,
+            "AAABAAAAAAAAAeJANj6qOGeEH7rQ9O2Ix3nk/mblaiRw3JjA7JwHPQXHsQM=",
+        ),
+    ];
+    for (s, b64) in cases {
+        let address = AccountAddress::from_string(s).unwrap();
+        assert_eq!(address.to_xdr_base64().as_str(), b64, "{s}");
+        assert_eq!(AccountAddress::from_xdr_base64(b64), Ok(address), "{s}");
+    }
+    assert_eq!(
+        AccountAddress::from_xdr(&Address::from_string(CONTRACT).unwrap().to_xdr()),
+        Err(DecodeError::Invalid)
+    );
+}
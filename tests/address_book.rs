@@ -0,0 +1,132 @@
+#![cfg(feature = "cli")]
+
+use stellar_strkey::{cli::address_book::AddressBook, *};
+
+const TREASURY: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+// Generated to share the first 5 and last 3 characters of TREASURY.
+const LOOKALIKE: &str = "GA3D55MRRFB6T2FUS7PB36WF7JAIY32J6OKWZFBT2H2QFLF46W3WVES5";
+// Generated to share the first 4 and last 4 characters of TREASURY.
+const LOOKALIKE_4: &str = "GA3DC4BGIHALX6MEPSZSRLVR4CI5DMN4KGTGAQ5CYXIKUTKK7E6AHES5";
+
+fn strkey(s: &str) -> Strkey {
+    Strkey::from_string(s).unwrap()
+}
+
+#[test]
+fn test_from_json() {
+    let book = AddressBook::from_json(&format!(
+        r#"{{"treasury":"{TREASURY}","pool":"{CONTRACT}"}}"#
+    ))
+    .unwrap();
+    assert_eq!(
+        book.entries().collect::<Vec<_>>(),
+        [("pool", &strkey(CONTRACT)), ("treasury", &strkey(TREASURY))]
+    );
+    assert_eq!(book.get("treasury"), Some(&strkey(TREASURY)));
+    assert_eq!(book.get("other"), None);
+    assert_eq!(book.label(&strkey(TREASURY)), Some("treasury"));
+    assert_eq!(book.label(&strkey(LOOKALIKE)), None);
+    assert_eq!(
+        book.to_json(),
+        format!("{{\n  \"pool\": \"{CONTRACT}\",\n  \"treasury\": \"{TREASURY}\"\n}}")
+    );
+    assert_eq!(AddressBook::from_json(&book.to_json()).unwrap(), book);
+    assert_eq!(
+        AddressBook::from_json("{}").unwrap(),
+        AddressBook::default()
+    );
+}
+
+#[test]
+fn test_from_json_invalid() {
+    // Strkeys are validated.
+    assert!(AddressBook::from_json(r#"{"treasury":"GA3D5"}"#).is_err());
+    assert!(AddressBook::from_json(&format!(r#"["{TREASURY}"]"#)).is_err());
+    // Labels appear once.
+    assert!(AddressBook::from_json(&format!(
+        r#"{{"treasury":"{TREASURY}","treasury":"{CONTRACT}"}}"#
+    ))
+    .is_err());
+    // Labels print on one line after a tab.
+    for label in ["", " treasury", "treasury ", "tre\tasury", "tre\nasury"] {
+        let json = serde_json::json!({ label: TREASURY }).to_string();
+        assert!(AddressBook::from_json(&json).is_err(), "{label:?}");
+    }
+    // Private keys are not addresses.
+    assert!(AddressBook::from_json(
+        r#"{"secret":"SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR"}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn test_insert() {
+    let mut book = AddressBook::default();
+    assert_eq!(book.insert("treasury", strkey(LOOKALIKE)).unwrap(), None);
+    assert_eq!(
+        book.insert("treasury", strkey(TREASURY)).unwrap(),
+        Some(strkey(LOOKALIKE))
+    );
+    assert!(book.insert("", strkey(CONTRACT)).is_err());
+    assert!(book
+        .insert(
+            "secret",
+            strkey("SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR")
+        )
+        .is_err());
+    assert_eq!(book.entries().count(), 1);
+}
+
+#[test]
+fn test_annotate() {
+    let mut book = AddressBook::default();
+    book.insert("Treasury wallet", strkey(TREASURY)).unwrap();
+    assert_eq!(
+        book.annotate(&strkey(TREASURY)),
+        format!("{TREASURY}\tTreasury wallet")
+    );
+    assert_eq!(book.annotate(&strkey(CONTRACT)), CONTRACT);
+
+    // What well-known strkeys are is not part of the address book.
+    let xlm = Strkey::Contract(well_known::XLM_PUBNET);
+    assert_eq!(book.annotate(&xlm), format!("{xlm}"));
+    book.insert("XLM", xlm.clone()).unwrap();
+    assert_eq!(book.annotate(&xlm), format!("{xlm}\tXLM"));
+}
+
+#[test]
+fn test_problems() {
+    let mut book = AddressBook::default();
+    book.insert("treasury", strkey(TREASURY)).unwrap();
+    book.insert("pool", strkey(CONTRACT)).unwrap();
+    // Sharing fewer than 4 trailing characters is not a problem.
+    book.insert("other", strkey(LOOKALIKE)).unwrap();
+    assert_eq!(book.problems(), Vec::<String>::new());
+
+    book.insert("again", strkey(TREASURY)).unwrap();
+    book.insert("poisoned", strkey(LOOKALIKE_4)).unwrap();
+    assert_eq!(
+        book.problems(),
+        [
+            format!("\"again\" {TREASURY} and \"poisoned\" {LOOKALIKE_4} look alike, 4 leading and 4 trailing characters match"),
+            format!("\"again\" and \"treasury\" are the same address {TREASURY}"),
+            format!("\"poisoned\" {LOOKALIKE_4} and \"treasury\" {TREASURY} look alike, 4 leading and 4 trailing characters match"),
+        ]
+    );
+}
+
+#[test]
+fn test_save() {
+    let dir = std::env::temp_dir().join(format!("stellar-strkey-book-{}", std::process::id()));
+    let path = dir.join("address-book.json");
+    let mut book = AddressBook::default();
+    book.insert("treasury", strkey(TREASURY)).unwrap();
+    book.save(&path).unwrap();
+    book.insert("pool", strkey(CONTRACT)).unwrap();
+    book.save(&path).unwrap();
+    assert_eq!(AddressBook::load(&path).unwrap(), book);
+    // The temporary file is renamed over the address book.
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,213 @@
+use std::fmt::Write;
+
+use stellar_strkey::*;
+
+const ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+fn issuer() -> ed25519::PublicKey {
+    ed25519::PublicKey::from_string(ISSUER).unwrap()
+}
+
+#[test]
+fn test_native() {
+    assert_eq!(Asset::from_string("native"), Ok(Asset::Native));
+    assert_eq!(Asset::Native.to_string(), "native");
+    assert_eq!(Asset::Native.code(), None);
+    assert_eq!(Asset::Native.issuer(), None);
+}
+
+#[test]
+fn test_alphanum4() {
+    let s = format!("USDC:{ISSUER}");
+    let asset = Asset::from_string(&s).unwrap();
+    assert_eq!(
+        asset,
+        Asset::AlphaNum4(AlphaNum4 {
+            code: *b"USDC",
+            issuer: issuer(),
+        })
+    );
+    assert_eq!(asset.to_string(), s);
+    assert_eq!(asset.code(), Some("USDC"));
+    assert_eq!(asset.issuer(), Some(&issuer()));
+
+    let s = format!("X:{ISSUER}");
+    let asset = Asset::from_string(&s).unwrap();
+    assert_eq!(
+        asset,
+        Asset::AlphaNum4(AlphaNum4 {
+            code: *b"X\0\0\0",
+            issuer: issuer(),
+        })
+    );
+    assert_eq!(asset.to_string(), s);
+}
+
+#[test]
+fn test_alphanum12() {
+    let s = format!("yUSDC:{ISSUER}");
+    let asset = Asset::from_string(&s).unwrap();
+    assert_eq!(
+        asset,
+        Asset::AlphaNum12(AlphaNum12 {
+            code: *b"yUSDC\0\0\0\0\0\0\0",
+            issuer: issuer(),
+        })
+    );
+    assert_eq!(asset.to_string(), s);
+
+    let s = format!("ABCDEFGHIJ12:{ISSUER}");
+    let asset = Asset::from_string(&s).unwrap();
+    assert_eq!(asset.code(), Some("ABCDEFGHIJ12"));
+    assert_eq!(asset.to_string(), s);
+}
+
+#[test]
+fn test_invalid() {
+    for s in [
+        "",
+        "XLM",
+        "Native",
+        "USDC",
+        "USDC:",
+        &format!(":{ISSUER}"),
+        &format!("USD-C:{ISSUER}"),
+        &format!("USD C:{ISSUER}"),
+        &format!("USDÇ:{ISSUER}"),
+        &format!("ABCDEFGHIJKLM:{ISSUER}"),
+        &format!("USDC:{ISSUER}:"),
+        "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVM",
+        "USDC:CA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
+    ] {
+        assert_eq!(Asset::from_string(s), Err(DecodeError::Invalid), "{s:?}");
+    }
+}
+
+#[test]
+fn test_code_rules() {
+    assert!(AlphaNum4::new("ABCD", issuer()).is_ok());
+    assert_eq!(AlphaNum4::new("", issuer()), Err(DecodeError::Invalid));
+    assert_eq!(AlphaNum4::new("ABCDE", issuer()), Err(DecodeError::Invalid));
+    assert_eq!(AlphaNum4::new("A\0B", issuer()), Err(DecodeError::Invalid));
+    // Trailing zeros are not taken as padding, so that codes parse canonically.
+    assert_eq!(AlphaNum4::new("USD\0", issuer()), Err(DecodeError::Invalid));
+    assert_eq!(
+        AlphaNum12::new("USDCX\0", issuer()),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(
+        Asset::from_string(&format!("USD\0:{ISSUER}")),
+        Err(DecodeError::Invalid)
+    );
+    assert!(AlphaNum12::new("ABCDE", issuer()).is_ok());
+    assert_eq!(AlphaNum12::new("ABCD", issuer()), Err(DecodeError::Invalid));
+    assert_eq!(
+        AlphaNum12::new("ABCDEFGHIJKLM", issuer()),
+        Err(DecodeError::Invalid)
+    );
+
+    // Codes are not valid if they contain zeros before the last character.
+    let a = AlphaNum4 {
+        code: *b"A\0B\0",
+        issuer: issuer(),
+    };
+    assert!(!a.is_valid());
+    let a = AlphaNum12 {
+        code: *b"ABCD\0\0\0\0\0\0\0\0",
+        issuer: issuer(),
+    };
+    assert!(!a.is_valid());
+    assert!(!Asset::AlphaNum12(a).is_valid());
+    assert!(Asset::Native.is_valid());
+
+    // Invalid codes are not displayed in any form.
+    for code in [*b"A\0B\0", *b"US-D", [0xff; 4], [0; 4]] {
+        let asset = Asset::AlphaNum4(AlphaNum4 {
+            code,
+            issuer: issuer(),
+        });
+        let mut s = String::new();
+        assert!(write!(s, "{asset}").is_err(), "{code:?}");
+        assert!(write!(s, "{}", asset.to_txrep()).is_err(), "{code:?}");
+        assert!(
+            write!(s, "{}", asset.to_caip19("pubnet")).is_err(),
+            "{code:?}"
+        );
+    }
+}
+
+#[test]
+fn test_ordering() {
+    let mut assets = [
+        Asset::from_string(&format!("ABCDE:{ISSUER}")).unwrap(),
+        Asset::from_string(&format!("USDC:{ISSUER}")).unwrap(),
+        Asset::from_string("USDC:GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5")
+            .unwrap(),
+        Asset::from_string(&format!("USD:{ISSUER}")).unwrap(),
+        Asset::Native,
+    ];
+    assets.sort();
+    assert_eq!(
+        assets.map(|a| a.to_string()),
+        [
+            "native".to_string(),
+            format!("USD:{ISSUER}"),
+            "USDC:GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5".to_string(),
+            format!("USDC:{ISSUER}"),
+            format!("ABCDE:{ISSUER}"),
+        ]
+    );
+}
+
+#[test]
+fn test_txrep() {
+    assert_eq!(Asset::from_txrep("XLM"), Ok(Asset::Native));
+    assert_eq!(Asset::from_txrep("native"), Err(DecodeError::Invalid));
+    assert_eq!(Asset::Native.to_txrep().to_string(), "XLM");
+
+    let s = format!("USDC:{ISSUER}");
+    let asset = Asset::from_txrep(&s).unwrap();
+    assert_eq!(asset, Asset::from_string(&s).unwrap());
+    assert_eq!(asset.to_txrep().to_string(), s);
+}
+
+#[test]
+fn test_caip19() {
+    assert_eq!(
+        Asset::from_caip19("stellar:pubnet/slip44:148"),
+        Ok(("pubnet", Asset::Native))
+    );
+    assert_eq!(
+        Asset::Native.to_caip19("testnet").to_string(),
+        "stellar:testnet/slip44:148"
+    );
+
+    let s = format!("stellar:pubnet/asset:USDC-{ISSUER}");
+    let (network, asset) = Asset::from_caip19(&s).unwrap();
+    assert_eq!(network, "pubnet");
+    assert_eq!(
+        asset,
+        Asset::from_string(&format!("USDC:{ISSUER}")).unwrap()
+    );
+    assert_eq!(asset.to_caip19("pubnet").to_string(), s);
+
+    for s in [
+        "stellar:pubnet",
+        "stellar:/slip44:148",
+        "stellar:pubnet/slip44:0",
+        "eip155:1/slip44:148",
+        &format!("stellar:pubnet/asset:USDC:{ISSUER}"),
+        &format!("stellar:pubnet/token:USDC-{ISSUER}"),
+    ] {
+        assert_eq!(Asset::from_caip19(s), Err(DecodeError::Invalid), "{s:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serde() {
+    let s = format!("USDC:{ISSUER}");
+    let asset = Asset::from_string(&s).unwrap();
+    serde_test::assert_tokens(&asset, &[serde_test::Token::Str(Box::leak(s.into()))]);
+    serde_test::assert_tokens(&Asset::Native, &[serde_test::Token::Str("native")]);
+}
@@ -0,0 +1,98 @@
+use stellar_strkey::*;
+
+const STRKEYS: [&str; 9] = [
+    "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5",
+    "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR",
+    "TA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZAM",
+    "XA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQH5FV",
+    "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAAAAAAPCICBKU",
+    "PA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAACQCAQDAQCQAAAARKDQ",
+    "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE",
+    "LA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZ5J",
+    "BAADMPVKHBTYIH522D2O3CGHPHSP4ZXFNISHBXEYYDWJYBZ5AXD3CA3GDE",
+];
+
+/// Decodes base32 without padding, as a reference for the binary form.
+fn unbase32(s: &str) -> Vec<u8> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    let mut bits = 0u32;
+    let mut len = 0;
+    let mut out = Vec::new();
+    for c in s.bytes() {
+        bits = (bits << 5) | ALPHABET.iter().position(|&a| a == c).unwrap() as u32;
+        len += 5;
+        if len >= 8 {
+            len -= 8;
+            out.push((bits >> len) as u8);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_binary() {
+    for s in STRKEYS {
+        let strkey = Strkey::from_string(s).unwrap();
+        let binary = strkey.to_binary();
+        assert_eq!(binary.as_slice(), unbase32(s), "{s}");
+        assert_eq!(Strkey::from_binary(&binary), Ok(strkey), "{s}");
+    }
+}
+
+#[test]
+fn test_binary_types() {
+    let [g, s, t, x, m, p, c, l, b] = STRKEYS;
+    let key = ed25519::PublicKey::from_string(g).unwrap();
+    assert_eq!(key.to_binary().len(), 35);
+    assert_eq!(key.to_binary().as_slice(), unbase32(g));
+    assert_eq!(ed25519::PublicKey::from_binary(&unbase32(g)), Ok(key));
+
+    macro_rules! roundtrip {
+        ($ty:ty, $s:expr) => {
+            let v = <$ty>::from_string($s).unwrap();
+            assert_eq!(v.to_binary().as_slice(), unbase32($s), "{}", $s);
+            assert_eq!(<$ty>::from_binary(&unbase32($s)), Ok(v.clone()), "{}", $s);
+            // Binary strkeys of other types are rejected.
+            assert_eq!(
+                <$ty>::from_binary(&unbase32(if $s == g { c } else { g })),
+                Err(DecodeError::Invalid)
+            );
+        };
+    }
+    roundtrip!(ed25519::PrivateKey, s);
+    roundtrip!(PreAuthTx, t);
+    roundtrip!(HashX, x);
+    roundtrip!(ed25519::MuxedAccount, m);
+    roundtrip!(ed25519::SignedPayload, p);
+    roundtrip!(Contract, c);
+    roundtrip!(LiquidityPool, l);
+    roundtrip!(ClaimableBalance, b);
+}
+
+#[test]
+fn test_binary_invalid() {
+    let binary = unbase32(STRKEYS[0]);
+
+    // Checksum mismatch.
+    for i in 0..binary.len() {
+        let mut corrupt = binary.clone();
+        corrupt[i] ^= 1;
+        assert_eq!(Strkey::from_binary(&corrupt), Err(DecodeError::Invalid));
+        assert_eq!(
+            ed25519::PublicKey::from_binary(&corrupt),
+            Err(DecodeError::Invalid)
+        );
+    }
+
+    // Truncated and extended.
+    assert_eq!(Strkey::from_binary(&[]), Err(DecodeError::Invalid));
+    assert_eq!(Strkey::from_binary(&binary[..2]), Err(DecodeError::Invalid));
+    assert_eq!(
+        Strkey::from_binary(&binary[..binary.len() - 1]),
+        Err(DecodeError::Invalid)
+    );
+    let mut extended = binary.clone();
+    extended.push(0);
+    assert_eq!(Strkey::from_binary(&extended), Err(DecodeError::Invalid));
+    assert_eq!(Strkey::from_binary(&[0; 200]), Err(DecodeError::Invalid));
+}
@@ -0,0 +1,98 @@
+use stellar_strkey::*;
+
+const ACCOUNT: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const MUXED: &str = "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAAAAAAPCICBKU";
+const CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+
+#[test]
+fn test_caip2() {
+    let pubnet = Caip2::from_string("stellar:pubnet").unwrap();
+    assert_eq!(pubnet, Caip2::pubnet());
+    assert_eq!(pubnet.reference(), Caip2::PUBNET);
+    assert_eq!(pubnet.to_string(), "stellar:pubnet");
+    assert_eq!("stellar:testnet".parse(), Ok(Caip2::testnet()));
+    assert_eq!(
+        Caip2::new("my-private_net1").unwrap().to_string(),
+        "stellar:my-private_net1"
+    );
+}
+
+#[test]
+fn test_caip2_invalid() {
+    for s in [
+        "stellar:",
+        "stellar",
+        "pubnet",
+        "eip155:1",
+        "stellar:pub net",
+        "stellar:pub.net",
+        "stellar:pubnet:",
+        "stellar:123456789012345678901234567890123",
+    ] {
+        assert_eq!(Caip2::from_string(s), Err(DecodeError::Invalid), "{s}");
+    }
+    assert!(Caip2::from_string("stellar:12345678901234567890123456789012").is_ok());
+}
+
+#[test]
+fn test_caip10() {
+    for address in [ACCOUNT, MUXED, CONTRACT] {
+        let s = format!("stellar:pubnet:{address}");
+        let id = Caip10::from_string(&s).unwrap();
+        assert_eq!(id.network(), &Caip2::pubnet());
+        assert_eq!(id.address(), &Strkey::from_string(address).unwrap());
+        assert_eq!(id.to_string(), s);
+        assert_eq!(s.parse(), Ok(id));
+    }
+
+    let id = Caip10::new(Caip2::testnet(), Strkey::from_string(CONTRACT).unwrap()).unwrap();
+    assert_eq!(id.to_string(), format!("stellar:testnet:{CONTRACT}"));
+}
+
+#[test]
+fn test_caip10_invalid_kinds() {
+    for address in [
+        "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR",
+        "TA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZAM",
+        "XA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQH5FV",
+        "LA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZ5J",
+        "BAADMPVKHBTYIH522D2O3CGHPHSP4ZXFNISHBXEYYDWJYBZ5AXD3CA3GDE",
+    ] {
+        let strkey = Strkey::from_string(address).unwrap();
+        assert_eq!(
+            Caip10::new(Caip2::pubnet(), strkey),
+            Err(DecodeError::UnexpectedKind),
+            "{address}"
+        );
+        assert_eq!(
+            Caip10::from_string(&format!("stellar:pubnet:{address}")),
+            Err(DecodeError::UnexpectedKind)
+        );
+    }
+}
+
+#[test]
+fn test_caip10_invalid() {
+    for s in [
+        ACCOUNT.to_string(),
+        format!("stellar:{ACCOUNT}"),
+        format!("eip155:1:{ACCOUNT}"),
+        format!("stellar::{ACCOUNT}"),
+        format!("stellar:pubnet:{}", &ACCOUNT[1..]),
+        format!("stellar:pubnet:{ACCOUNT}:"),
+    ] {
+        assert_eq!(Caip10::from_string(&s), Err(DecodeError::Invalid), "{s}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let s = "stellar:pubnet:GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+    let id = Caip10::from_string(s).unwrap();
+    serde_test::assert_tokens(&id, &[serde_test::Token::Str(s)]);
+    serde_test::assert_tokens(
+        &Caip2::testnet(),
+        &[serde_test::Token::Str("stellar:testnet")],
+    );
+}
@@ -0,0 +1,103 @@
+use stellar_strkey::*;
+
+const KEY: &str = "363eaa3867841fbad0f4ed88c779e4fe66e56a2470dc98c0ec9c073d05c7b103";
+const ACCOUNT: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const MUXED_ACCOUNT: &str = "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAAAAAAPCICBKU";
+const CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+const CLAIMABLE_BALANCE: &str = "BAADMPVKHBTYIH522D2O3CGHPHSP4ZXFNISHBXEYYDWJYBZ5AXD3CA3GDE";
+const LIQUIDITY_POOL: &str = "LA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZ5J";
+
+fn detect(s: &str, hex_kind: Option<HexKind>) -> Result<(String, Format), DecodeError> {
+    Strkey::detect(s, hex_kind).map(|(strkey, format)| (strkey.to_string().to_string(), format))
+}
+
+#[test]
+fn test_detect() {
+    let ok = |strkey: &str, format| Ok((strkey.to_string(), format));
+    assert_eq!(detect(ACCOUNT, None), ok(ACCOUNT, Format::Strkey));
+    assert_eq!(
+        detect(&format!("  {MUXED_ACCOUNT}\n"), None),
+        ok(MUXED_ACCOUNT, Format::Strkey)
+    );
+    assert_eq!(
+        detect(&format!("stellar:pubnet:{CONTRACT}"), None),
+        ok(CONTRACT, Format::Caip10(Caip2::pubnet()))
+    );
+    assert_eq!(
+        detect(KEY, Some(HexKind::PublicKey)),
+        ok(ACCOUNT, Format::Hex)
+    );
+    assert_eq!(
+        detect(&KEY.to_uppercase(), Some(HexKind::Contract)),
+        ok(CONTRACT, Format::Hex)
+    );
+    assert_eq!(
+        detect(&format!("0x{KEY}"), Some(HexKind::Contract)),
+        ok(CONTRACT, Format::PrefixedHex)
+    );
+    assert_eq!(
+        detect(&format!("0x{KEY}"), None),
+        Err(DecodeError::AmbiguousKind)
+    );
+    assert_eq!(
+        detect(&format!("00000000{KEY}"), None),
+        ok(CLAIMABLE_BALANCE, Format::HorizonClaimableBalance)
+    );
+    // Hex alone does not say what kind of key it is.
+    assert_eq!(detect(KEY, None), Err(DecodeError::AmbiguousKind));
+    assert_eq!(
+        detect(KEY, Some(HexKind::LiquidityPool)),
+        ok(LIQUIDITY_POOL, Format::Hex)
+    );
+
+    for invalid in [
+        "",
+        &ACCOUNT[1..],
+        &KEY[2..],
+        "stellar:pubnet:GA3D",
+        "0x1234",
+    ] {
+        assert!(
+            detect(invalid, Some(HexKind::PublicKey)).is_err(),
+            "{invalid}"
+        );
+    }
+}
+
+#[cfg(feature = "xdr")]
+#[test]
+fn test_detect_xdr() {
+    // Vectors generated with the stellar-xdr crate.
+    let ok = |strkey: &str, format| Ok((strkey.to_string(), format));
+    assert_eq!(
+        detect("AAAAADY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED", None),
+        ok(ACCOUNT, Format::AccountIdXdr)
+    );
+    assert_eq!(
+        detect(
+            "AAABAAAAAAAAAeJANj6qOGeEH7rQ9O2Ix3nk/mblaiRw3JjA7JwHPQXHsQM=",
+            None
+        ),
+        ok(MUXED_ACCOUNT, Format::MuxedAccountXdr)
+    );
+    assert_eq!(
+        detect("AAAAATY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED", None),
+        ok(CONTRACT, Format::ScAddressXdr)
+    );
+    assert_eq!(
+        detect(
+            "AAAAAAAAAAA2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAw==",
+            None
+        ),
+        ok(ACCOUNT, Format::ScAddressXdr)
+    );
+}
+
+#[test]
+fn test_format_display() {
+    assert_eq!(Format::Strkey.to_string(), "strkey");
+    assert_eq!(
+        Format::Caip10(Caip2::testnet()).to_string(),
+        "CAIP-10 account ID on stellar:testnet"
+    );
+}
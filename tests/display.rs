@@ -1,5 +1,15 @@
 use stellar_strkey::*;
 
+const ACCOUNT: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const MUXED_ACCOUNT: &str = "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAAAAAAPCICBKU";
+const CLAIMABLE_BALANCE: &str = "BAADMPVKHBTYIH522D2O3CGHPHSP4ZXFNISHBXEYYDWJYBZ5AXD3CA3GDE";
+const SIGNED_PAYLOAD: &str =
+    "PA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAACQCAQDAQCQAAAARKDQ";
+
+fn strkey(s: &str) -> Strkey {
+    Strkey::from_string(s).unwrap()
+}
+
 #[test]
 fn test_strkey_ed25519_public_key_display() {
     let strkey = Strkey::PublicKeyEd25519(ed25519::PublicKey([
@@ -245,3 +255,98 @@ fn test_claimable_balance_display() {
         "BAADMPVKHBTYIH522D2O3CGHPHSP4ZXFNISHBXEYYDWJYBZ5AXD3CA3GDE"
     );
 }
+
+#[test]
+fn test_display_honors_formatter() {
+    let key = ed25519::PublicKey::from_string(ACCOUNT).unwrap();
+    assert_eq!(format!("{key:>60}"), format!("    {ACCOUNT}"));
+    assert_eq!(format!("{key:*<58}"), format!("{ACCOUNT}**"));
+    assert_eq!(format!("{key:.4}"), "GA3D");
+    assert_eq!(format!("{:^58}", strkey(ACCOUNT)), format!(" {ACCOUNT} "));
+    assert_eq!(format!("{:>6.3}", Contract([0; 32])), "   CAA");
+    assert_eq!(
+        format!("{:>70}", strkey(MUXED_ACCOUNT)),
+        format!(" {MUXED_ACCOUNT}")
+    );
+}
+
+#[test]
+fn test_checksum_chars() {
+    for s in [ACCOUNT, MUXED_ACCOUNT, CLAIMABLE_BALANCE, SIGNED_PAYLOAD] {
+        let strkey = strkey(s);
+        let n = strkey.checksum_chars();
+        assert!((4..=5).contains(&n), "{s}");
+        // Changing a character before the checksum characters changes the
+        // payload, while the checksum characters alone are enough to change
+        // the checksum.
+        let binary = strkey.to_binary();
+        let first_checksum_bit = (binary.len() - 2) * 8;
+        assert!((s.len() - n) * 5 <= first_checksum_bit, "{s}");
+        assert!((s.len() - n + 1) * 5 > first_checksum_bit, "{s}");
+    }
+}
+
+#[test]
+fn test_display_short() {
+    let account = strkey(ACCOUNT);
+    assert_eq!(account.display_short_default().to_string(), "GA3D…HES5");
+    assert_eq!(account.display_short(6, 6).to_string(), "GA3D5K…YQHES5");
+    // The tail always includes the checksum.
+    assert_eq!(account.display_short(1, 0).to_string(), "G…HES5");
+    assert_eq!(account.display_short(0, 2).to_string(), "…HES5");
+    // Too long to abbreviate.
+    assert_eq!(account.display_short(30, 25).to_string(), ACCOUNT);
+    assert_eq!(account.display_short(30, 26).to_string(), ACCOUNT);
+    assert_eq!(
+        account.display_short(usize::MAX, usize::MAX).to_string(),
+        ACCOUNT
+    );
+    assert_eq!(
+        account.display_short(30, 24).to_string(),
+        format!("{}…{}", &ACCOUNT[..30], &ACCOUNT[32..])
+    );
+    assert_eq!(
+        format!("{:>12}", account.display_short_default()),
+        "   GA3D…HES5"
+    );
+
+    assert_eq!(
+        strkey(MUXED_ACCOUNT).display_short_default().to_string(),
+        "MA3D…CBKU"
+    );
+}
+
+#[test]
+fn test_display_grouped() {
+    let account = strkey(ACCOUNT);
+    assert_eq!(
+        account.display_grouped(4).to_string(),
+        "GA3D 5KRY M6CB 7OWQ 6TWY RR3Z 4T7G NZLK ERYN ZGGA 5SOA OPIF Y6YQ HES5"
+    );
+    assert_eq!(
+        account.display_grouped(5).to_string(),
+        "GA3D5 KRYM6 CB7OW Q6TWY RR3Z4 T7GNZ LKERY NZGGA 5SOAO PIFY6 YQHES 5"
+    );
+    assert_eq!(account.display_grouped(0).to_string(), ACCOUNT);
+    assert_eq!(account.display_grouped(100).to_string(), ACCOUNT);
+
+    // The longest strkey.
+    let signed_payload = ed25519::SignedPayload::new(
+        ed25519::PublicKey::from_string(ACCOUNT).unwrap(),
+        &[0xff; 64],
+    )
+    .unwrap();
+    let s = signed_payload.to_string();
+    assert_eq!(s.len(), 165);
+    let grouped = Strkey::SignedPayloadEd25519(signed_payload)
+        .display_grouped(1)
+        .to_string();
+    assert_eq!(grouped.len(), 165 * 2 - 1);
+    assert_eq!(grouped.replace(' ', ""), s.as_str());
+}
+
+#[test]
+fn test_short_id() {
+    assert_eq!(strkey(ACCOUNT).short_id().as_str(), "GA3D5");
+    assert_eq!(strkey(CLAIMABLE_BALANCE).short_id().as_str(), "BAADM");
+}
@@ -0,0 +1,56 @@
+use stellar_strkey::*;
+
+const HASH: &str = "da0d57da7d4850e7fc10d2a9d0ebc731f7afb40574c03395b17d49149b91f5be";
+const CLAIMABLE_BALANCE: &str = "BAANUDKX3J6UQUHH7QINFKOQ5PDTD55PWQCXJQBTSWYX2SIUTOI7LPQEI4";
+const LIQUIDITY_POOL: &str = "LDNA2V62PVEFBZ74CDJKTUHLY4Y7PL5UAV2MAM4VWF6USFE3SH234BPX";
+
+#[test]
+fn test_claimable_balance_horizon_hex() {
+    let hex = format!("00000000{HASH}");
+    let balance = ClaimableBalance::from_string(CLAIMABLE_BALANCE).unwrap();
+    assert_eq!(balance.to_horizon_hex().as_str(), hex);
+    assert_eq!(ClaimableBalance::from_horizon_hex(&hex), Ok(balance));
+    assert_eq!(
+        ClaimableBalance::from_horizon_hex(&hex.to_uppercase()),
+        Ok(balance)
+    );
+
+    for invalid in [
+        // Unknown discriminant.
+        format!("00000001{HASH}"),
+        // Missing discriminant.
+        HASH.to_string(),
+        format!("00000000{}", &HASH[2..]),
+        format!("00000000{HASH}00"),
+        format!("00000000{}zz", &HASH[2..]),
+    ] {
+        assert_eq!(
+            ClaimableBalance::from_horizon_hex(&invalid),
+            Err(DecodeError::Invalid),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn test_liquidity_pool_horizon_hex() {
+    let pool = LiquidityPool::from_string(LIQUIDITY_POOL).unwrap();
+    assert_eq!(pool.to_horizon_hex().as_str(), HASH);
+    assert_eq!(LiquidityPool::from_horizon_hex(HASH), Ok(pool));
+    assert_eq!(
+        LiquidityPool::from_horizon_hex(&HASH.to_uppercase()),
+        Ok(pool)
+    );
+
+    for invalid in [
+        format!("00000000{HASH}"),
+        HASH[2..].to_string(),
+        format!("{}zz", &HASH[2..]),
+    ] {
+        assert_eq!(
+            LiquidityPool::from_horizon_hex(&invalid),
+            Err(DecodeError::Invalid),
+            "{invalid}"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "cli")]
+
+use std::{fs, path::PathBuf, sync::Once};
+
+use stellar_strkey::{
+    cli::{self, keys},
+    ed25519, Strkey,
+};
+
+const ADDRESS: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const SECRET: &str = "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR";
+
+/// Points the keys directory at an empty temporary directory shared by the
+/// tests, which use distinct identity names.
+fn keys_dir() -> PathBuf {
+    static INIT: Once = Once::new();
+    let dir = std::env::temp_dir().join(format!("stellar-strkey-keys-{}", std::process::id()));
+    INIT.call_once(|| {
+        let _ = fs::remove_dir_all(&dir);
+        std::env::set_var("STELLAR_STRKEY_KEYS_DIR", &dir);
+    });
+    dir
+}
+
+fn add_address(name: &str, address: &str) {
+    cli::run(["stellar-strkey", "keys", "add", name, "--address", address]).unwrap();
+}
+
+#[test]
+fn test_resolve() {
+    keys_dir();
+    // Strkeys resolve to themselves without reading the keys directory.
+    assert_eq!(keys::resolve(ADDRESS).unwrap(), ADDRESS);
+
+    add_address("resolve-address", ADDRESS);
+    assert_eq!(keys::resolve("@resolve-address").unwrap(), ADDRESS);
+
+    let dir = keys_dir();
+    fs::create_dir_all(&dir).unwrap();
+    let secret = ed25519::PrivateKey::from_string(SECRET).unwrap();
+    let json = serde_json::to_string(&keys::Identity::Secret(secret)).unwrap();
+    fs::write(dir.join("resolve-secret.json"), json).unwrap();
+    // Secrets resolve to their address, never the secret.
+    assert_eq!(
+        keys::resolve("@resolve-secret").unwrap(),
+        format!("{}", Strkey::PublicKeyEd25519(secret.public_key()))
+    );
+
+    assert!(matches!(
+        keys::resolve("@resolve-missing"),
+        Err(keys::Error::NotFound(name)) if name == "resolve-missing"
+    ));
+}
+
+#[test]
+fn test_invalid_names() {
+    keys_dir();
+    for name in ["", "../escape", "a/b", "a b", "a.json", "ñ"] {
+        assert!(
+            matches!(keys::load(name), Err(keys::Error::InvalidName(n)) if n == name),
+            "{name:?}"
+        );
+        assert!(matches!(
+            keys::resolve(&format!("@{name}")),
+            Err(keys::Error::InvalidName(_))
+        ));
+        assert!(matches!(
+            cli::run(["stellar-strkey", "keys", "add", name, "--address", ADDRESS]),
+            Err(cli::Error::Keys(keys::Error::InvalidName(_)))
+        ));
+    }
+}
+
+#[test]
+fn test_add_exists() {
+    keys_dir();
+    add_address("exists", ADDRESS);
+    assert!(matches!(
+        cli::run([
+            "stellar-strkey",
+            "keys",
+            "add",
+            "exists",
+            "--address",
+            ADDRESS
+        ]),
+        Err(cli::Error::Keys(keys::Error::Exists(_)))
+    ));
+    // Secrets are not addresses to watch.
+    assert!(matches!(
+        cli::run([
+            "stellar-strkey",
+            "keys",
+            "add",
+            "not-address",
+            "--address",
+            SECRET
+        ]),
+        Err(cli::Error::Keys(keys::Error::NotAnAddress(_)))
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_file_mode() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = keys_dir();
+    add_address("mode", ADDRESS);
+    let mode = fs::metadata(dir.join("mode.json"))
+        .unwrap()
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
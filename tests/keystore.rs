@@ -0,0 +1,180 @@
+#![cfg(feature = "keystore")]
+
+use stellar_strkey::{
+    ed25519,
+    keystore::{ChaCha20Poly1305Params, Cipher, Error, Kdf, Keystore, ScryptParams},
+};
+
+const SECRET: &str = "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR";
+const ADDRESS: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const PASSWORD: &[u8] = b"correct horse battery staple";
+
+// Generated independently with Python's hashlib.scrypt and
+// cryptography.hazmat.primitives.ciphers.aead.ChaCha20Poly1305.
+const KAT: &str = r#"{
+  "version": 1,
+  "address": "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5",
+  "kdf": {
+    "scrypt": {
+      "log_n": 10,
+      "r": 8,
+      "p": 1,
+      "salt": "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+    }
+  },
+  "cipher": {
+    "chacha20poly1305": {
+      "nonce": "000102030405060708090a0b"
+    }
+  },
+  "ciphertext": "2d632bb3c9f04e46d7e9ef47ed70562f95376908451905678c724180a5f38f8ce0d12d8db9aee76cb3d9ce6959ff803e"
+}"#;
+
+fn kat_params() -> (ScryptParams, [u8; 12]) {
+    let salt: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let nonce: [u8; 12] = core::array::from_fn(|i| i as u8);
+    (
+        ScryptParams {
+            log_n: 10,
+            r: 8,
+            p: 1,
+            salt,
+        },
+        nonce,
+    )
+}
+
+#[test]
+fn test_public_key() {
+    let key = ed25519::PrivateKey::from_string(SECRET).unwrap();
+    assert_eq!(key.public_key().to_string().as_str(), ADDRESS);
+}
+
+#[test]
+fn test_kat_encrypt() {
+    let key = ed25519::PrivateKey::from_string(SECRET).unwrap();
+    let (params, nonce) = kat_params();
+    let keystore = Keystore::encrypt_with_salt_and_nonce(&key, PASSWORD, params, nonce).unwrap();
+    assert_eq!(serde_json::to_string_pretty(&keystore).unwrap(), KAT);
+}
+
+#[test]
+fn test_kat_decrypt() {
+    let keystore: Keystore = serde_json::from_str(KAT).unwrap();
+    assert_eq!(keystore.address.to_string().as_str(), ADDRESS);
+    assert_eq!(
+        keystore.decrypt(PASSWORD),
+        Ok(ed25519::PrivateKey::from_string(SECRET).unwrap())
+    );
+}
+
+#[test]
+fn test_roundtrip_random() {
+    let key = ed25519::PrivateKey::from_string(SECRET).unwrap();
+    let a = Keystore::encrypt_with_cost(&key, PASSWORD, 10, 8, 1).unwrap();
+    let b = Keystore::encrypt_with_cost(&key, PASSWORD, 10, 8, 1).unwrap();
+    assert_ne!(a, b);
+    assert_eq!(a.decrypt(PASSWORD), Ok(key));
+    assert_eq!(b.decrypt(PASSWORD), Ok(key));
+}
+
+#[test]
+fn test_wrong_password() {
+    let keystore: Keystore = serde_json::from_str(KAT).unwrap();
+    assert_eq!(keystore.decrypt(b"wrong"), Err(Error::Decrypt));
+}
+
+#[test]
+fn test_tampered() {
+    let keystore: Keystore = serde_json::from_str(KAT).unwrap();
+
+    let mut k = keystore.clone();
+    k.ciphertext[0] ^= 1;
+    assert_eq!(k.decrypt(PASSWORD), Err(Error::Decrypt));
+
+    let mut k = keystore.clone();
+    k.ciphertext[47] ^= 1;
+    assert_eq!(k.decrypt(PASSWORD), Err(Error::Decrypt));
+
+    let mut k = keystore.clone();
+    k.address.0[0] ^= 1;
+    assert_eq!(k.decrypt(PASSWORD), Err(Error::Decrypt));
+
+    let mut k = keystore.clone();
+    let Cipher::ChaCha20Poly1305(ChaCha20Poly1305Params { nonce }) = &mut k.cipher;
+    nonce[0] ^= 1;
+    assert_eq!(k.decrypt(PASSWORD), Err(Error::Decrypt));
+
+    let mut k = keystore.clone();
+    let Kdf::Scrypt(params) = &mut k.kdf;
+    params.salt[0] ^= 1;
+    assert_eq!(k.decrypt(PASSWORD), Err(Error::Decrypt));
+}
+
+#[test]
+fn test_unsupported_version() {
+    let mut keystore: Keystore = serde_json::from_str(KAT).unwrap();
+    keystore.version = 2;
+    assert_eq!(
+        keystore.decrypt(PASSWORD),
+        Err(Error::UnsupportedVersion(2))
+    );
+}
+
+#[test]
+fn test_address_mismatch() {
+    // The address is bound to the ciphertext as associated data, but is not
+    // the public key of the encrypted private key.
+    let json = KAT
+        .replace(
+            ADDRESS,
+            "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
+        )
+        .replace(
+            "2d632bb3c9f04e46d7e9ef47ed70562f95376908451905678c724180a5f38f8ce0d12d8db9aee76cb3d9ce6959ff803e",
+            "2d632bb3c9f04e46d7e9ef47ed70562f95376908451905678c724180a5f38f8cfce59b5697e8316714100c9bba5bffa6",
+        );
+    let keystore: Keystore = serde_json::from_str(&json).unwrap();
+    assert_eq!(keystore.decrypt(PASSWORD), Err(Error::AddressMismatch));
+}
+
+#[test]
+fn test_invalid_kdf_params() {
+    let mut keystore: Keystore = serde_json::from_str(KAT).unwrap();
+    let Kdf::Scrypt(params) = &mut keystore.kdf;
+    params.log_n = ScryptParams::MAX_LOG_N + 1;
+    assert_eq!(keystore.decrypt(PASSWORD), Err(Error::InvalidKdfParams));
+    let Kdf::Scrypt(params) = &mut keystore.kdf;
+    params.log_n = 10;
+    params.p = 0;
+    assert_eq!(keystore.decrypt(PASSWORD), Err(Error::InvalidKdfParams));
+}
+
+#[test]
+fn test_kdf_params_bounded() {
+    // A keystore with a huge r would need 128 * 2^20 * 2^20 bytes, 128 TiB.
+    let mut keystore: Keystore = serde_json::from_str(KAT).unwrap();
+    let Kdf::Scrypt(params) = &mut keystore.kdf;
+    params.log_n = ScryptParams::MAX_LOG_N;
+    params.r = 1 << 20;
+    assert_eq!(keystore.decrypt(PASSWORD), Err(Error::InvalidKdfParams));
+    let Kdf::Scrypt(params) = &mut keystore.kdf;
+    params.r = ScryptParams::MAX_R + 1;
+    assert_eq!(keystore.decrypt(PASSWORD), Err(Error::InvalidKdfParams));
+    // A huge p would need unbounded work.
+    let Kdf::Scrypt(params) = &mut keystore.kdf;
+    params.r = ScryptParams::DEFAULT_R;
+    params.p = u32::MAX;
+    assert_eq!(keystore.decrypt(PASSWORD), Err(Error::InvalidKdfParams));
+    let Kdf::Scrypt(params) = &mut keystore.kdf;
+    params.p = ScryptParams::MAX_P + 1;
+    assert_eq!(keystore.decrypt(PASSWORD), Err(Error::InvalidKdfParams));
+}
+
+#[test]
+fn test_unknown_kdf_and_cipher() {
+    let json = KAT.replace("\"scrypt\"", "\"argon2id\"");
+    assert!(serde_json::from_str::<Keystore>(&json).is_err());
+    let json = KAT.replace("\"chacha20poly1305\"", "\"aes256gcm\"");
+    assert!(serde_json::from_str::<Keystore>(&json).is_err());
+}
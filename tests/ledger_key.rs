@@ -0,0 +1,138 @@
+#![cfg(feature = "xdr")]
+
+use stellar_strkey::*;
+
+// Vectors generated with the stellar-xdr crate.
+
+const ACCOUNT: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+const CLAIMABLE_BALANCE: &str = "BAADMPVKHBTYIH522D2O3CGHPHSP4ZXFNISHBXEYYDWJYBZ5AXD3CA3GDE";
+const LIQUIDITY_POOL: &str = "LA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGZ5J";
+
+fn contract() -> Contract {
+    Contract::from_string(CONTRACT).unwrap()
+}
+
+#[test]
+fn test_ledger_key() {
+    let cases = [
+        (
+            LedgerKey::from(ed25519::PublicKey::from_string(ACCOUNT).unwrap()),
+            "AAAAAAAAAAA2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAw==",
+        ),
+        (
+            LedgerKey::from(ClaimableBalance::from_string(CLAIMABLE_BALANCE).unwrap()),
+            "AAAABAAAAAA2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAw==",
+        ),
+        (
+            LedgerKey::from(LiquidityPool::from_string(LIQUIDITY_POOL).unwrap()),
+            "AAAABTY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED",
+        ),
+        (
+            LedgerKey::contract_instance(contract()),
+            "AAAABgAAAAE2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAwAAABQAAAAB",
+        ),
+        (
+            LedgerKey::ContractData {
+                contract: contract(),
+                key: ContractDataKey::symbol("Admin").unwrap(),
+                durability: ContractDataDurability::Temporary,
+            },
+            "AAAABgAAAAE2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAwAAAA8AAAAFQWRtaW4AAAAAAAAA",
+        ),
+        (
+            // Vec[Symbol("Balance"), Address(CONTRACT)]
+            LedgerKey::ContractData {
+                contract: contract(),
+                key: ContractDataKey::from_xdr_base64(
+                    "AAAAEAAAAAEAAAACAAAADwAAAAdCYWxhbmNlAAAAABIAAAABNj6qOGeEH7rQ9O2Ix3nk/mblaiRw3JjA7JwHPQXHsQM=",
+                )
+                .unwrap(),
+                durability: ContractDataDurability::Persistent,
+            },
+            "AAAABgAAAAE2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAwAAABAAAAABAAAAAgAAAA8AAAAHQmFsYW5jZQAAAAASAAAAATY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7EDAAAAAQ==",
+        ),
+    ];
+    for (key, b64) in cases {
+        assert_eq!(key.to_xdr_base64().as_str(), b64, "{key:?}");
+    }
+}
+
+#[test]
+fn test_contract_data_key() {
+    assert_eq!(
+        ContractDataKey::symbol("Admin")
+            .unwrap()
+            .to_xdr()
+            .as_slice(),
+        [0, 0, 0, 15, 0, 0, 0, 5, b'A', b'd', b'm', b'i', b'n', 0, 0, 0]
+    );
+    assert_eq!(
+        ContractDataKey::symbol("abcdefghijklmnopqrstuvwxyz_01234")
+            .unwrap()
+            .to_xdr()
+            .len(),
+        40
+    );
+    assert_eq!(
+        ContractDataKey::symbol("abcdefghijklmnopqrstuvwxyz_012345"),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(
+        ContractDataKey::symbol("no-dashes"),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(ContractDataKey::Instance.to_xdr().as_slice(), [0, 0, 0, 20]);
+
+    assert_eq!(ContractDataKey::from_xdr(&[]), Err(DecodeError::Invalid));
+    assert_eq!(
+        ContractDataKey::from_xdr(&[0, 0, 0]),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(
+        ContractDataKey::from_xdr(&[0; ContractDataKey::MAX_XDR_LEN + 4]),
+        Err(DecodeError::Invalid)
+    );
+    assert!(ContractDataKey::from_xdr(&[0; ContractDataKey::MAX_XDR_LEN]).is_ok());
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_durability() {
+    use stellar_strkey::cli::{self, ledger_key};
+    let run = |strkey: &str, durability: &str| {
+        cli::run([
+            "stellar-strkey",
+            "ledger-key",
+            strkey,
+            "--durability",
+            durability,
+        ])
+    };
+    assert!(run(CONTRACT, "persistent").is_ok());
+    for strkey in [ACCOUNT, CLAIMABLE_BALANCE, LIQUIDITY_POOL] {
+        assert!(
+            matches!(
+                run(strkey, "persistent"),
+                Err(cli::Error::LedgerKey(ledger_key::Error::NotContractDurability(s))) if s == strkey
+            ),
+            "{strkey}"
+        );
+    }
+    // The contract is not printed before the account is rejected.
+    assert!(matches!(
+        cli::run([
+            "stellar-strkey",
+            "ledger-key",
+            CONTRACT,
+            ACCOUNT,
+            "--durability",
+            "persistent",
+        ]),
+        Err(cli::Error::LedgerKey(ledger_key::Error::NotContractDurability(s))) if s == ACCOUNT
+    ));
+    assert!(matches!(
+        run(CONTRACT, "temporary"),
+        Err(cli::Error::LedgerKey(ledger_key::Error::InstanceDurability))
+    ));
+}
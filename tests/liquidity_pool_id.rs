@@ -0,0 +1,98 @@
+#![cfg(feature = "sha2")]
+
+use stellar_strkey::*;
+
+const USDC: &str = "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+const ABCDEFGH: &str = "ABCDEFGH:GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+
+#[test]
+fn test_native_alphanum4() {
+    let pool = LiquidityPool::from_constant_product(
+        &Asset::Native,
+        &USDC.parse().unwrap(),
+        LiquidityPool::FEE_V18,
+    );
+    assert_eq!(
+        pool,
+        Ok(LiquidityPool([
+            0xa4, 0x68, 0xd4, 0x1d, 0x8e, 0x9b, 0x8f, 0x3c, 0x72, 0x09, 0x65, 0x16, 0x08, 0xb7,
+            0x4b, 0x7d, 0xb7, 0xac, 0x99, 0x52, 0xdc, 0xae, 0x0c, 0xdf, 0x24, 0x87, 0x1d, 0x1d,
+            0x9c, 0x7b, 0x00, 0x88,
+        ]))
+    );
+    assert_eq!(
+        pool.unwrap().to_string().as_str(),
+        "LCSGRVA5R2NY6PDSBFSRMCFXJN63PLEZKLOK4DG7ESDR2HM4PMAIQQUG"
+    );
+}
+
+#[test]
+fn test_alphanum4_alphanum12() {
+    let pool = LiquidityPool::from_constant_product(
+        &USDC.parse().unwrap(),
+        &ABCDEFGH.parse().unwrap(),
+        LiquidityPool::FEE_V18,
+    )
+    .unwrap();
+    assert_eq!(
+        pool.to_string().as_str(),
+        "LCWVY3IBKK2L2JVSOE6IFU7KET65IE5Q5I3U7LUWS3ZZRDWTBL4HPS5O"
+    );
+}
+
+#[test]
+fn test_fee() {
+    let pool =
+        LiquidityPool::from_constant_product(&Asset::Native, &USDC.parse().unwrap(), 10).unwrap();
+    assert_eq!(
+        pool.to_string().as_str(),
+        "LBTBHKNFU3TZYHF6GTZTTX33IZCJJZ6NHN6CKMM7OSGNKTHYF7SRSM54"
+    );
+}
+
+#[test]
+fn test_assets_out_of_order() {
+    let usdc: Asset = USDC.parse().unwrap();
+    let abcdefgh: Asset = ABCDEFGH.parse().unwrap();
+    assert_eq!(
+        LiquidityPool::from_constant_product(&usdc, &Asset::Native, LiquidityPool::FEE_V18),
+        Err(LiquidityPoolIdError::AssetsOutOfOrder)
+    );
+    assert_eq!(
+        LiquidityPool::from_constant_product(&abcdefgh, &usdc, LiquidityPool::FEE_V18),
+        Err(LiquidityPoolIdError::AssetsOutOfOrder)
+    );
+
+    // Same type, ordered by code before issuer.
+    let usdb: Asset = "USDB:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"
+        .parse()
+        .unwrap();
+    let usdc_other: Asset = "USDC:GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5"
+        .parse()
+        .unwrap();
+    assert!(LiquidityPool::from_constant_product(&usdb, &usdc, LiquidityPool::FEE_V18).is_ok());
+    assert_eq!(
+        LiquidityPool::from_constant_product(&usdc, &usdc_other, LiquidityPool::FEE_V18),
+        Err(LiquidityPoolIdError::AssetsOutOfOrder)
+    );
+    assert!(
+        LiquidityPool::from_constant_product(&usdc_other, &usdc, LiquidityPool::FEE_V18).is_ok()
+    );
+}
+
+#[test]
+fn test_assets_identical() {
+    let usdc: Asset = USDC.parse().unwrap();
+    assert_eq!(
+        LiquidityPool::from_constant_product(&usdc, &usdc, LiquidityPool::FEE_V18),
+        Err(LiquidityPoolIdError::AssetsIdentical)
+    );
+    assert_eq!(
+        LiquidityPool::from_constant_product(
+            &Asset::Native,
+            &Asset::Native,
+            LiquidityPool::FEE_V18
+        ),
+        Err(LiquidityPoolIdError::AssetsIdentical)
+    );
+}
@@ -0,0 +1,75 @@
+use stellar_strkey::*;
+
+const KNOWN: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+// Generated to share the first 5 and last 3 characters of KNOWN.
+const LOOKALIKE: &str = "GA3D55MRRFB6T2FUS7PB36WF7JAIY32J6OKWZFBT2H2QFLF46W3WVES5";
+
+fn strkey(s: &str) -> Strkey {
+    Strkey::from_string(s).unwrap()
+}
+
+#[test]
+fn test_similarity() {
+    let similarity = strkey(LOOKALIKE).similarity(&strkey(KNOWN));
+    assert_eq!(similarity, Similarity { head: 5, tail: 3 });
+    assert_eq!(similarity.score(), 8);
+    assert_eq!(strkey(KNOWN).similarity(&strkey(LOOKALIKE)), similarity);
+
+    assert_eq!(
+        strkey(KNOWN).similarity(&strkey(KNOWN)),
+        Similarity { head: 56, tail: 56 }
+    );
+    assert_eq!(
+        strkey(KNOWN).similarity(&strkey(
+            "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE"
+        )),
+        Similarity { head: 0, tail: 0 }
+    );
+    // Strkeys of different lengths are compared from both ends.
+    assert_eq!(
+        strkey(KNOWN).similarity(&strkey(
+            "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAAAAAAPCICBKU"
+        )),
+        Similarity { head: 0, tail: 0 }
+    );
+}
+
+#[test]
+fn test_lookalike() {
+    let known = strkey(KNOWN);
+    let lookalike = strkey(LOOKALIKE);
+    assert_eq!(
+        lookalike.lookalike(&known, 3),
+        Some(Similarity { head: 5, tail: 3 })
+    );
+    assert_eq!(lookalike.lookalike(&known, 4), None);
+    assert_eq!(
+        lookalike.lookalike(&known, Similarity::DEFAULT_MIN_MATCH),
+        None
+    );
+    // A strkey is not a lookalike of itself.
+    assert_eq!(known.lookalike(&known, 0), None);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_min_match() {
+    use stellar_strkey::cli;
+    let known = std::env::temp_dir().join(format!("stellar-strkey-known-{}", std::process::id()));
+    std::fs::write(&known, format!("{KNOWN}\n")).unwrap();
+    let run = |min_match: &str| {
+        cli::run([
+            "stellar-strkey",
+            "lookalike",
+            LOOKALIKE,
+            "--known",
+            known.to_str().unwrap(),
+            "--min-match",
+            min_match,
+        ])
+    };
+    assert!(run("4").is_ok());
+    // Every address would look like every known address.
+    assert!(matches!(run("0"), Err(cli::Error::Clap(_))));
+    std::fs::remove_file(&known).unwrap();
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "message")]
+
+use stellar_strkey::{ed25519, message};
+
+// Test vectors from SEP-53, with the signatures hex encoded.
+const SECRET: &str = "SAKICEVQLYWGSOJS4WW7HZJWAHZVEEBS527LHK5V4MLJALYKICQCJXMW";
+const ADDRESS: &str = "GBXFXNDLV4LSWA4VB7YIL5GBD7BVNR22SGBTDKMO2SBZZHDXSKZYCP7L";
+
+const VECTORS: &[(&[u8], &str, &str)] = &[
+    (
+        b"Hello, World!",
+        "d52eb59c06bb510d065997ff93077068eed0a486c20215b5e02e1ab0d2ebea5f",
+        "7cee5d6d885752104c85eea421dfdcb95abf01f1271d11c4bec3fcbd7874dccd6e2e98b97b8eb23b643cac4073bb77de5d07b0710139180ae9f3cbba78f2ba04",
+    ),
+    (
+        "こんにちは、世界！".as_bytes(),
+        "7bde4f792e336ed43df42ad66a92b44cb1bc60708e8bee63494c289dee161682",
+        "083536eb95ecf32dce59b07fe7a1fd8cf814b2ce46f40d2a16e4ea1f6cecd980e04e6fbef9d21f98011c785a81edb85f3776a6e7d942b435eb0adc07da4d4604",
+    ),
+    (
+        &[0xff, 0xd8, 0xff, 0xe0, 0x00],
+        "d3c48b5fbd7b0ac162960f69782a94686eeaa204180fb55100f51ba8b7ea5e6c",
+        "8856b09e62ea016f36200f578cd9e13ad617ab61d0785f2bb93f4403aa1d458438876f47d5989ec7b9225d0321dac61f4beb320b996ea25ac36bfeaf0461940d",
+    ),
+];
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn signature(s: &str) -> [u8; 64] {
+    let mut sig = [0u8; 64];
+    for (i, b) in sig.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+    }
+    sig
+}
+
+#[test]
+fn test_sign() {
+    let key = ed25519::PrivateKey::from_string(SECRET).unwrap();
+    for (msg, hash, sig) in VECTORS {
+        assert_eq!(hex(&message::hash(msg)), *hash);
+        assert_eq!(message::sign(&key, msg), signature(sig));
+    }
+}
+
+#[test]
+fn test_verify() {
+    let key = ed25519::PublicKey::from_string(ADDRESS).unwrap();
+    for (msg, _, sig) in VECTORS {
+        assert_eq!(message::verify(&key, msg, &signature(sig)), Ok(()));
+    }
+}
+
+#[test]
+fn test_verify_wrong_message() {
+    let key = ed25519::PublicKey::from_string(ADDRESS).unwrap();
+    let (_, _, sig) = VECTORS[0];
+    assert_eq!(
+        message::verify(&key, b"Hello, World?", &signature(sig)),
+        Err(message::Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_verify_wrong_key() {
+    let key =
+        ed25519::PublicKey::from_string("GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5")
+            .unwrap();
+    let (msg, _, sig) = VECTORS[0];
+    assert_eq!(
+        message::verify(&key, msg, &signature(sig)),
+        Err(message::Error::InvalidSignature)
+    );
+}
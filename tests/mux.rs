@@ -0,0 +1,47 @@
+#![cfg(feature = "cli")]
+
+use stellar_strkey::cli;
+
+const ACCOUNT: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+const MUXED_ACCOUNT: &str = "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAAAAAAPCICBKU";
+
+#[test]
+fn test_mux_count() {
+    assert!(cli::run(["stellar-strkey", "mux", ACCOUNT, "1", "--count", "2"]).is_ok());
+    assert!(matches!(
+        cli::run(["stellar-strkey", "mux", ACCOUNT, "1", "--count", "0"]),
+        Err(cli::Error::Clap(_))
+    ));
+    assert!(matches!(
+        cli::run([
+            "stellar-strkey",
+            "mux",
+            ACCOUNT,
+            &u64::MAX.to_string(),
+            "--count",
+            "2"
+        ]),
+        Err(cli::Error::Mux(cli::mux::Error::IdOverflow(u64::MAX, 2)))
+    ));
+}
+
+#[test]
+fn test_demux_resolves_names() {
+    let dir = std::env::temp_dir().join(format!("stellar-strkey-mux-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::env::set_var("STELLAR_STRKEY_KEYS_DIR", &dir);
+    cli::run([
+        "stellar-strkey",
+        "keys",
+        "add",
+        "muxed",
+        "--address",
+        MUXED_ACCOUNT,
+    ])
+    .unwrap();
+    assert!(cli::run(["stellar-strkey", "demux", "@muxed"]).is_ok());
+    assert!(matches!(
+        cli::run(["stellar-strkey", "demux", "@missing"]),
+        Err(cli::Error::Demux(cli::demux::Error::Keys(_)))
+    ));
+}
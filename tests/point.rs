@@ -0,0 +1,72 @@
+#![cfg(feature = "curve25519-dalek")]
+
+use stellar_strkey::*;
+
+#[test]
+fn test_valid_point() {
+    let s = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+    let key = ed25519::PublicKey::from_string(s).unwrap();
+    assert!(key.is_canonical_point());
+    assert_eq!(key.check_point(), Ok(()));
+    assert_eq!(ed25519::PublicKey::from_string_strict(s), Ok(key));
+}
+
+#[test]
+fn test_not_on_curve() {
+    // y = 2 is not the y-coordinate of any point on the curve.
+    let s = "GABAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABVCX";
+    let key = ed25519::PublicKey::from_string(s).unwrap();
+    assert!(!key.is_canonical_point());
+    assert_eq!(key.check_point(), Err(DecodeError::NotOnCurve));
+    assert_eq!(
+        ed25519::PublicKey::from_string_strict(s),
+        Err(DecodeError::NotOnCurve)
+    );
+}
+
+#[test]
+fn test_non_canonical() {
+    // y = p + 3, which decodes to the same point as y = 3.
+    let s = "GDYP77777777777777777777777777777777777777777777777X7ZME";
+    let key = ed25519::PublicKey::from_string(s).unwrap();
+    assert!(!key.is_canonical_point());
+    assert_eq!(key.check_point(), Err(DecodeError::NonCanonicalPoint));
+
+    // y = p + 1, a non-canonical encoding of the identity.
+    let s = "GDXP77777777777777777777777777777777777777777777777X7Z3N";
+    assert_eq!(
+        ed25519::PublicKey::from_string_strict(s),
+        Err(DecodeError::NonCanonicalPoint)
+    );
+}
+
+#[test]
+fn test_small_order() {
+    for s in [
+        // The all zero key, y = 0, a point of order 4.
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+        // The identity, y = 1.
+        "GAAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHV4",
+        // y = -1, a point of order 2.
+        "GDWP77777777777777777777777777777777777777777777777X6267",
+    ] {
+        let key = ed25519::PublicKey::from_string(s).unwrap();
+        assert!(!key.is_canonical_point(), "{s}");
+        assert_eq!(key.check_point(), Err(DecodeError::SmallOrderPoint), "{s}");
+        assert_eq!(
+            ed25519::PublicKey::from_string_strict(s),
+            Err(DecodeError::SmallOrderPoint),
+            "{s}"
+        );
+    }
+}
+
+#[test]
+fn test_strict_invalid_strkey() {
+    assert_eq!(
+        ed25519::PublicKey::from_string_strict(
+            "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES4"
+        ),
+        Err(DecodeError::Invalid)
+    );
+}
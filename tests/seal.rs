@@ -0,0 +1,84 @@
+#![cfg(feature = "seal")]
+
+use stellar_strkey::{ed25519, seal, DecodeError};
+
+const SECRET: &str = "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR";
+const ADDRESS: &str = "GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5";
+
+// Generated independently with Python, using u = (1 + y) / (1 - y) for the
+// public key and the clamped SHA-512 of the seed for the private key, and
+// checked with cryptography's X25519PrivateKey.
+#[test]
+fn test_x25519() {
+    let private = ed25519::PrivateKey::from_string(SECRET).unwrap();
+    let public = ed25519::PublicKey::from_string(ADDRESS).unwrap();
+    assert_eq!(
+        private.to_x25519(),
+        [
+            0xa0, 0xab, 0xe3, 0x3b, 0x12, 0x06, 0x15, 0xb5, 0x4c, 0xa8, 0xb5, 0xd7, 0x1e, 0x23,
+            0x93, 0x9e, 0xda, 0x0e, 0xfd, 0x24, 0xaf, 0xf5, 0x5e, 0x63, 0xa1, 0x08, 0xfb, 0xd3,
+            0x1d, 0x21, 0x66, 0x65,
+        ]
+    );
+    assert_eq!(
+        public.to_x25519(),
+        Ok([
+            0xea, 0xd1, 0x73, 0x9d, 0x67, 0x9c, 0xbb, 0x36, 0x9a, 0x12, 0xb3, 0x62, 0xd2, 0xf7,
+            0x38, 0xff, 0xc3, 0xbc, 0x1c, 0x3b, 0x0e, 0x2e, 0xf9, 0x21, 0x26, 0x2d, 0xfa, 0xf2,
+            0x2d, 0x94, 0x20, 0x58,
+        ])
+    );
+}
+
+#[test]
+fn test_x25519_invalid_public_key() {
+    let public =
+        ed25519::PublicKey::from_string("GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")
+            .unwrap();
+    assert_eq!(public.to_x25519(), Err(DecodeError::SmallOrderPoint));
+    assert_eq!(
+        seal::seal(&public, b"hello"),
+        Err(seal::Error::InvalidPublicKey(DecodeError::SmallOrderPoint))
+    );
+}
+
+#[test]
+fn test_seal_open() {
+    let private = ed25519::PrivateKey::from_string(SECRET).unwrap();
+    let public = ed25519::PublicKey::from_string(ADDRESS).unwrap();
+    let message = b"memo payload";
+
+    let a = seal::seal(&public, message).unwrap();
+    let b = seal::seal(&public, message).unwrap();
+    assert_eq!(a.len(), message.len() + seal::OVERHEAD);
+    assert_ne!(a, b);
+    assert_eq!(seal::open(&private, &a).unwrap(), message);
+    assert_eq!(seal::open(&private, &b).unwrap(), message);
+
+    let empty = seal::seal(&public, b"").unwrap();
+    assert_eq!(seal::open(&private, &empty).unwrap(), b"");
+}
+
+#[test]
+fn test_open_wrong_key() {
+    let public = ed25519::PublicKey::from_string(ADDRESS).unwrap();
+    let other = ed25519::PrivateKey([1; 32]);
+    let ciphertext = seal::seal(&public, b"memo payload").unwrap();
+    assert_eq!(seal::open(&other, &ciphertext), Err(seal::Error::Open));
+}
+
+#[test]
+fn test_open_modified() {
+    let private = ed25519::PrivateKey::from_string(SECRET).unwrap();
+    let public = ed25519::PublicKey::from_string(ADDRESS).unwrap();
+    let ciphertext = seal::seal(&public, b"memo payload").unwrap();
+    for i in [0, 31, 32, 47, ciphertext.len() - 1] {
+        let mut c = ciphertext.clone();
+        c[i] ^= 1;
+        assert_eq!(seal::open(&private, &c), Err(seal::Error::Open), "{i}");
+    }
+    assert_eq!(
+        seal::open(&private, &ciphertext[..seal::OVERHEAD - 1]),
+        Err(seal::Error::Open)
+    );
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "shares")]
+
+use stellar_strkey::{
+    ed25519,
+    shares::{self, Error, Share},
+    DecodeError,
+};
+
+const SECRET: &str = "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR";
+
+// Generated independently with Python, using fixed polynomial coefficients.
+const SHARES_2_OF_3: [&str; 3] = [
+    "stellar-share-2-1-axd3ca3hxxmore6ho22udvncxlhazia3j7tr4a6mdvogealow5dmvdatqqick",
+    "stellar-share-2-2-axd3ca3vql6i33muh32iwas6k5ulomeczvzzdllz4w7yrqfs4aakp7biw2bae",
+    "stellar-share-2-3-axd3ca33s7qk5r5fa3f42tykbqfn4qhvwp3b2pxdiqlso5qpetfxkjoikgibu",
+];
+const SHARES_3_OF_5: [&str; 5] = [
+    "stellar-share-3-1-axd3ca34t7y5rjhzgp4rfd6d2kqxvxm7yr2ypi3lwpu55qvem2pbk2x6oaifk",
+    "stellar-share-3-2-axd3cayzbjme2mlmghp5y4ob5thxjx5e24g4gg6tnnou5ynxrfg7msnrjpenu",
+    "stellar-share-3-3-axd3caymhvwv4lddjswmszxu37bgxusxeinnmkhomraf3fgatrppw5v4lbavm",
+    "stellar-share-3-4-axd3ca725ijguth7slnegyhxjsk7xgrtxkr4obewaddxfxif6grsf7qmbnkte",
+    "stellar-share-3-5-axd3ca7p3utxsupq56uvm56cp6mojf6aj62nen5lb7ngdkds4syc7qibddol4",
+];
+
+fn parse(shares: &[&str]) -> Vec<Share> {
+    shares
+        .iter()
+        .map(|s| Share::from_string(s).unwrap())
+        .collect()
+}
+
+fn key() -> ed25519::PrivateKey {
+    ed25519::PrivateKey::from_string(SECRET).unwrap()
+}
+
+#[test]
+fn test_encoding_roundtrip() {
+    for s in SHARES_2_OF_3.iter().chain(&SHARES_3_OF_5) {
+        let share = Share::from_string(s).unwrap();
+        assert_eq!(share.to_string().as_str(), *s);
+        assert_eq!(
+            format!("{share:>width$}", width = s.len() + 2),
+            format!("  {s}")
+        );
+        assert_eq!(format!("{share:.4}"), &s[..4]);
+    }
+    let share = Share::from_string(SHARES_3_OF_5[1]).unwrap();
+    assert_eq!(share.threshold, 3);
+    assert_eq!(share.index, 2);
+    assert_eq!(share.public_key_hint, [0x05, 0xc7, 0xb1, 0x03]);
+}
+
+#[test]
+fn test_kat_combine() {
+    let all = parse(&SHARES_2_OF_3);
+    for pair in [[0, 1], [1, 2], [2, 0]] {
+        let shares = [all[pair[0]].clone(), all[pair[1]].clone()];
+        assert_eq!(shares::combine(&shares), Ok(key()));
+    }
+    let all = parse(&SHARES_3_OF_5);
+    for triple in [[0, 1, 2], [4, 2, 0], [1, 3, 4]] {
+        let shares = triple.map(|i| all[i].clone());
+        assert_eq!(shares::combine(&shares), Ok(key()));
+    }
+    assert_eq!(shares::combine(&all), Ok(key()));
+}
+
+#[test]
+fn test_split_roundtrip() {
+    let shares = shares::split(&key(), 3, 5).unwrap();
+    assert_eq!(shares.len(), 5);
+    for (i, share) in shares.iter().enumerate() {
+        assert_eq!(share.index as usize, i + 1);
+        assert_eq!(share.threshold, 3);
+        assert_eq!(Share::from_string(&share.to_string()), Ok(share.clone()));
+    }
+    assert_eq!(shares::combine(&shares[2..]), Ok(key()));
+    assert_eq!(
+        shares::combine(&[shares[4].clone(), shares[0].clone(), shares[3].clone()]),
+        Ok(key())
+    );
+}
+
+#[test]
+fn test_split_invalid_threshold() {
+    assert_eq!(shares::split(&key(), 1, 3), Err(Error::InvalidThreshold));
+    assert_eq!(shares::split(&key(), 4, 3), Err(Error::InvalidThreshold));
+    assert!(shares::split(&key(), 255, 255).is_ok());
+}
+
+#[test]
+fn test_combine_errors() {
+    let all = parse(&SHARES_3_OF_5);
+    assert_eq!(shares::combine(&[]), Err(Error::NotEnoughShares));
+    assert_eq!(shares::combine(&all[..2]), Err(Error::NotEnoughShares));
+    assert_eq!(
+        shares::combine(&[all[0].clone(), all[1].clone(), all[0].clone()]),
+        Err(Error::DuplicateShare)
+    );
+    let other = parse(&SHARES_2_OF_3);
+    assert_eq!(
+        shares::combine(&[all[0].clone(), all[1].clone(), other[2].clone()]),
+        Err(Error::MismatchedShares)
+    );
+
+    // A share with a modified value recombines to a different key.
+    let mut modified = all[2].clone();
+    modified.value[0] ^= 1;
+    assert_eq!(
+        shares::combine(&[all[0].clone(), all[1].clone(), modified]),
+        Err(Error::KeyMismatch)
+    );
+}
+
+#[test]
+fn test_decode_invalid() {
+    let s = SHARES_2_OF_3[0];
+    // Not a share.
+    assert_eq!(Share::from_string(SECRET), Err(DecodeError::Invalid));
+    // Checksum covers the threshold and index.
+    let changed = s.replace("-2-1-", "-2-2-");
+    assert_eq!(Share::from_string(&changed), Err(DecodeError::Invalid));
+    let changed = s.replace("-2-1-", "-3-1-");
+    assert_eq!(Share::from_string(&changed), Err(DecodeError::Invalid));
+    // Checksum covers the data.
+    let changed = s.replace("axd3ca3h", "axd3ca3i");
+    assert_eq!(Share::from_string(&changed), Err(DecodeError::Invalid));
+    // Uppercase, truncated and out of range forms.
+    assert_eq!(
+        Share::from_string(&s.to_uppercase()),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(
+        Share::from_string(&s[..s.len() - 1]),
+        Err(DecodeError::Invalid)
+    );
+    let changed = s.replace("-2-1-", "-2-0-");
+    assert_eq!(Share::from_string(&changed), Err(DecodeError::Invalid));
+    let changed = s.replace("-2-1-", "-2-256-");
+    assert_eq!(Share::from_string(&changed), Err(DecodeError::Invalid));
+    let changed = s.replace("-2-1-", "-2-+1-");
+    assert_eq!(Share::from_string(&changed), Err(DecodeError::Invalid));
+}
@@ -0,0 +1,82 @@
+use stellar_strkey::{ed25519, SignedPayloadError};
+
+// Key and signatures generated independently with Python's cryptography
+// package, from the SEP-53 test vector secret
+// SAKICEVQLYWGSOJS4WW7HZJWAHZVEEBS527LHK5V4MLJALYKICQCJXMW.
+const ADDRESS: &str = "GBXFXNDLV4LSWA4VB7YIL5GBD7BVNR22SGBTDKMO2SBZZHDXSKZYCP7L";
+const SIGNER: &str = "PBXFXNDLV4LSWA4VB7YIL5GBD7BVNR22SGBTDKMO2SBZZHDXSKZYCAAAAAQOFNWFUHY6HSHUDZO5BQ6W4OQ3FRGV433QQGJKHNGF23T7QCIQEAZCYQ";
+const PAYLOAD: [u8; 32] = [
+    0xe2, 0xb6, 0xc5, 0xa1, 0xf1, 0xe3, 0xc8, 0xf4, 0x1e, 0x5d, 0xd0, 0xc3, 0xd6, 0xe3, 0xa1, 0xb2,
+    0xc4, 0xd5, 0xe6, 0xf7, 0x08, 0x19, 0x2a, 0x3b, 0x4c, 0x5d, 0x6e, 0x7f, 0x80, 0x91, 0x02, 0x03,
+];
+#[cfg(feature = "ed25519-dalek")]
+const SIGNATURE: [u8; 64] = [
+    0x08, 0xed, 0xca, 0xd2, 0x41, 0x77, 0x3a, 0xef, 0xa2, 0x74, 0x6d, 0x24, 0x55, 0xe2, 0xb8, 0xb2,
+    0xb0, 0xb4, 0xa7, 0x4d, 0xbc, 0x77, 0x8b, 0x5e, 0xfe, 0x63, 0x65, 0x8d, 0x13, 0xc4, 0x75, 0xdf,
+    0xb4, 0x06, 0x96, 0x6f, 0xd2, 0x4e, 0xe7, 0x4f, 0x04, 0x72, 0xda, 0x96, 0x0d, 0x26, 0x0d, 0x47,
+    0x66, 0x94, 0x68, 0xa6, 0xa4, 0xa4, 0x55, 0x77, 0x8e, 0xe7, 0x08, 0x3e, 0x51, 0x69, 0xa5, 0x0b,
+];
+
+fn signer() -> ed25519::SignedPayload {
+    let key = ed25519::PublicKey::from_string(ADDRESS).unwrap();
+    ed25519::SignedPayload::new(key, &PAYLOAD).unwrap()
+}
+
+#[test]
+fn test_new() {
+    let signer = signer();
+    assert_eq!(signer.to_string().as_str(), SIGNER);
+    assert_eq!(signer.public_key().to_string().as_str(), ADDRESS);
+    assert_eq!(signer.payload.as_slice(), &PAYLOAD);
+}
+
+#[test]
+fn test_new_payload_sizes() {
+    let key = ed25519::PublicKey::from_string(ADDRESS).unwrap();
+    assert_eq!(
+        ed25519::SignedPayload::new(key, &[]),
+        Err(SignedPayloadError::PayloadEmpty)
+    );
+    assert!(ed25519::SignedPayload::new(key, &[0; 1]).is_ok());
+    assert!(ed25519::SignedPayload::new(key, &[0; 64]).is_ok());
+    assert_eq!(
+        ed25519::SignedPayload::new(key, &[0; 65]),
+        Err(SignedPayloadError::PayloadTooLong)
+    );
+}
+
+#[test]
+fn test_hint() {
+    assert_eq!(signer().hint(), [0xf7, 0x03, 0xb1, 0x82]);
+
+    // The SEP-23 signed payload test vector.
+    let signer = ed25519::SignedPayload::from_string("PA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUAAAAAQACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB6IBZGM").unwrap();
+    assert_eq!(signer.hint(), [0xe1, 0x61, 0xf7, 0xba]);
+}
+
+#[test]
+fn test_hint_short_payload() {
+    // Payloads shorter than 4 bytes are padded with zeros on the right.
+    let key = ed25519::PublicKey([
+        0x3f, 0x0c, 0x34, 0xbf, 0x93, 0xad, 0x0d, 0x99, 0x71, 0xd0, 0x4c, 0xcc, 0x90, 0xf7, 0x05,
+        0x51, 0x1c, 0x83, 0x8a, 0xad, 0x97, 0x34, 0xa4, 0xa2, 0xfb, 0x0d, 0x7a, 0x03, 0xfc, 0x7f,
+        0xe8, 0x9a,
+    ]);
+    let signer = ed25519::SignedPayload::new(key, &[0x01, 0x02]).unwrap();
+    assert_eq!(signer.hint(), [0xfd, 0x7d, 0xe8, 0x9a]);
+}
+
+#[cfg(feature = "ed25519-dalek")]
+#[test]
+fn test_is_satisfied_by() {
+    let signer = signer();
+    assert!(signer.is_satisfied_by(&SIGNATURE));
+
+    let mut tampered = SIGNATURE;
+    tampered[0] ^= 1;
+    assert!(!signer.is_satisfied_by(&tampered));
+
+    let mut other = signer.clone();
+    other.payload[0] ^= 1;
+    assert!(!other.is_satisfied_by(&SIGNATURE));
+}
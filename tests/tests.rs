@@ -192,6 +192,36 @@ fn test_invalid_muxed_ed25519() {
     assert_eq!(r, Err(DecodeError::Invalid));
 }
 
+#[test]
+fn test_muxed_ed25519_account_and_id() {
+    let account =
+        ed25519::PublicKey::from_string("GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5")
+            .unwrap();
+    let muxed = ed25519::MuxedAccount::from_string(
+        "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAAAAAAAAAPCICBKU",
+    )
+    .unwrap();
+    assert_eq!(ed25519::MuxedAccount::new(account, 123456), muxed);
+    assert_eq!(account.muxed(123456), muxed);
+    assert_eq!(ed25519::MuxedAccount::from((account, 123456)), muxed);
+    assert_eq!(muxed.public_key(), account);
+    assert_eq!(<(ed25519::PublicKey, u64)>::from(muxed), (account, 123456));
+
+    // IDs are encoded big endian.
+    assert_eq!(
+        account.muxed(u64::MAX).to_string().as_str(),
+        "MA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQH7777777777775DQK"
+    );
+    assert_eq!(
+        ed25519::MuxedAccount::from_string(
+            "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVAAAAAAAAAAAAAJLK"
+        )
+        .unwrap()
+        .id,
+        9223372036854775808
+    );
+}
+
 #[test]
 fn test_valid_signed_payload_ed25519() {
     assert_convert_roundtrip(
@@ -406,8 +436,8 @@ fn test_invalid_signed_payload_ed25519() {
 fn test_signed_payload_ed25519_payload_sizes() {
     for payload_size in 1..=64 {
         let mut payload_arr = [0u8; 64];
-        for i in 0..payload_size {
-            payload_arr[i] = i as u8;
+        for (i, b) in payload_arr[..payload_size].iter_mut().enumerate() {
+            *b = i as u8;
         }
 
         let signed_payload = Strkey::SignedPayloadEd25519(ed25519::SignedPayload {
@@ -430,7 +460,7 @@ fn test_signed_payload_ed25519_payload_sizes() {
         let padding = (4 - payload_size % 4) % 4;
         let payload_len = 32 + 4 + payload_size + padding;
         let binary_len = 1 + payload_len + 2; // version + payload + crc
-        let expected_encoded_len = (binary_len * 8 + 4) / 5;
+        let expected_encoded_len = (binary_len * 8).div_ceil(5);
         assert_eq!(encoded.len(), expected_encoded_len);
     }
 }
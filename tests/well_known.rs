@@ -0,0 +1,126 @@
+use stellar_strkey::{
+    well_known::{self, Network, WellKnown},
+    *,
+};
+
+fn strkey(s: &str) -> Strkey {
+    Strkey::from_string(s).unwrap()
+}
+
+#[test]
+fn test_xlm_contracts() {
+    assert_eq!(
+        well_known::XLM_PUBNET.to_string(),
+        "CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA"
+    );
+    assert_eq!(
+        well_known::XLM_TESTNET.to_string(),
+        "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"
+    );
+    assert_eq!(
+        well_known::XLM_FUTURENET.to_string(),
+        "CB64D3G7SM2RTH6JSGG34DDTFTQ5CFDKVDZJZSODMCX4NJ2HV2KN7OHT"
+    );
+    assert_eq!(Network::Pubnet.xlm_contract(), well_known::XLM_PUBNET);
+    assert_eq!(Network::Testnet.xlm_contract(), well_known::XLM_TESTNET);
+    assert_eq!(Network::Futurenet.xlm_contract(), well_known::XLM_FUTURENET);
+}
+
+/// Derives the contract ID of the XLM Stellar Asset Contract from the
+/// network passphrase, as the SHA-256 hash of the XDR `HashIDPreimage` of
+/// `ENVELOPE_TYPE_CONTRACT_ID` with a `CONTRACT_ID_PREIMAGE_FROM_ASSET` of
+/// `ASSET_TYPE_NATIVE`.
+#[cfg(feature = "sha2")]
+#[test]
+fn test_xlm_contracts_derived() {
+    use sha2::{Digest, Sha256};
+    const ENVELOPE_TYPE_CONTRACT_ID: u32 = 8;
+    const CONTRACT_ID_PREIMAGE_FROM_ASSET: u32 = 1;
+    const ASSET_TYPE_NATIVE: u32 = 0;
+    for network in Network::ALL {
+        let network_id = Sha256::digest(network.passphrase());
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(&ENVELOPE_TYPE_CONTRACT_ID.to_be_bytes());
+        preimage.extend_from_slice(&network_id);
+        preimage.extend_from_slice(&CONTRACT_ID_PREIMAGE_FROM_ASSET.to_be_bytes());
+        preimage.extend_from_slice(&ASSET_TYPE_NATIVE.to_be_bytes());
+        let contract = Contract(Sha256::digest(&preimage).into());
+        assert_eq!(network.xlm_contract(), contract, "{network}");
+    }
+}
+
+#[test]
+fn test_zero() {
+    let zeros = well_known::ALL
+        .iter()
+        .filter(|w| w.name.starts_with("zero "))
+        .collect::<Vec<_>>();
+    // One for each kind of strkey, except private keys.
+    assert_eq!(zeros.len(), 8);
+    for w in zeros {
+        assert_eq!(w.network, None);
+        // Everything between the version byte and the checksum is zero,
+        // including the length of a signed payload.
+        let binary = w.strkey.to_binary();
+        assert!(binary[1..binary.len() - 2].iter().all(|b| *b == 0), "{w}");
+    }
+    assert_eq!(
+        Strkey::PublicKeyEd25519(well_known::ZERO_ACCOUNT).to_string(),
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"
+    );
+    assert_eq!(
+        well_known::ZERO_CONTRACT.to_string(),
+        "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4"
+    );
+}
+
+#[test]
+fn test_lookup() {
+    for w in &well_known::ALL {
+        assert_eq!(well_known::lookup(&w.strkey), Some(w));
+        assert_eq!(w.strkey.well_known(), Some(w));
+        // Every entry is a distinct strkey.
+        assert_eq!(
+            well_known::ALL
+                .iter()
+                .filter(|o| o.strkey == w.strkey)
+                .count(),
+            1
+        );
+    }
+    assert_eq!(
+        strkey("CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA").well_known(),
+        Some(&WellKnown {
+            name: "XLM Stellar Asset Contract",
+            network: Some(Network::Pubnet),
+            strkey: Strkey::Contract(well_known::XLM_PUBNET),
+        })
+    );
+    assert_eq!(
+        strkey("GA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQHES5").well_known(),
+        None
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(
+        Strkey::Contract(well_known::XLM_TESTNET)
+            .well_known()
+            .unwrap()
+            .to_string(),
+        "XLM Stellar Asset Contract on testnet"
+    );
+    assert_eq!(
+        Strkey::PublicKeyEd25519(well_known::ZERO_ACCOUNT)
+            .well_known()
+            .unwrap()
+            .to_string(),
+        "zero account"
+    );
+    assert_eq!(Network::Futurenet.to_string(), "futurenet");
+    assert_eq!(
+        Network::Testnet.passphrase(),
+        "Test SDF Network ; September 2015"
+    );
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "xdr")]
+
+use stellar_strkey::*;
+
+// Vectors generated with the stellar-xdr crate.
+
+const KEY: &str = "363eaa3867841fbad0f4ed88c779e4fe66e56a2470dc98c0ec9c073d05c7b103";
+
+fn unhex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn key() -> [u8; 32] {
+    unhex(KEY).try_into().unwrap()
+}
+
+#[test]
+fn test_public_key() {
+    let key = ed25519::PublicKey(key());
+    let xdr = unhex(&format!("00000000{KEY}"));
+    let b64 = "AAAAADY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED";
+    assert_eq!(key.to_xdr().as_slice(), xdr);
+    assert_eq!(key.to_xdr_base64().as_str(), b64);
+    assert_eq!(ed25519::PublicKey::from_xdr(&xdr), Ok(key));
+    assert_eq!(ed25519::PublicKey::from_xdr_base64(b64), Ok(key));
+
+    let xdr = unhex(&format!("0000000000000000{KEY}"));
+    let b64 = "AAAAAAAAAAA2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAw==";
+    assert_eq!(key.to_sc_address_xdr().as_slice(), xdr);
+    assert_eq!(key.to_sc_address_xdr_base64().as_str(), b64);
+    assert_eq!(ed25519::PublicKey::from_sc_address_xdr(&xdr), Ok(key));
+    assert_eq!(ed25519::PublicKey::from_sc_address_xdr_base64(b64), Ok(key));
+}
+
+#[test]
+fn test_muxed_account() {
+    let muxed = ed25519::MuxedAccount {
+        ed25519: key(),
+        id: 0x0102030405060708,
+    };
+    let xdr = unhex(&format!("000001000102030405060708{KEY}"));
+    let b64 = "AAABAAECAwQFBgcINj6qOGeEH7rQ9O2Ix3nk/mblaiRw3JjA7JwHPQXHsQM=";
+    assert_eq!(muxed.to_xdr().as_slice(), xdr);
+    assert_eq!(muxed.to_xdr_base64().as_str(), b64);
+    assert_eq!(ed25519::MuxedAccount::from_xdr(&xdr), Ok(muxed));
+    assert_eq!(ed25519::MuxedAccount::from_xdr_base64(b64), Ok(muxed));
+
+    let xdr = unhex(&format!("000000020102030405060708{KEY}"));
+    let b64 = "AAAAAgECAwQFBgcINj6qOGeEH7rQ9O2Ix3nk/mblaiRw3JjA7JwHPQXHsQM=";
+    assert_eq!(muxed.to_sc_address_xdr().as_slice(), xdr);
+    assert_eq!(muxed.to_sc_address_xdr_base64().as_str(), b64);
+    assert_eq!(ed25519::MuxedAccount::from_sc_address_xdr(&xdr), Ok(muxed));
+    assert_eq!(
+        ed25519::MuxedAccount::from_sc_address_xdr_base64(b64),
+        Ok(muxed)
+    );
+
+    // A MuxedAccount holding a plain ed25519 key is not a muxed account.
+    assert_eq!(
+        ed25519::MuxedAccount::from_xdr(&unhex(&format!("00000000{KEY}"))),
+        Err(DecodeError::Invalid)
+    );
+}
+
+#[test]
+fn test_sc_address() {
+    let xdr = unhex(&format!("00000001{KEY}"));
+    let b64 = "AAAAATY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED";
+    let contract = Contract(key());
+    assert_eq!(contract.to_xdr().as_slice(), xdr);
+    assert_eq!(contract.to_xdr_base64().as_str(), b64);
+    assert_eq!(Contract::from_xdr(&xdr), Ok(contract));
+    assert_eq!(Contract::from_xdr_base64(b64), Ok(contract));
+
+    let xdr = unhex(&format!("0000000300000000{KEY}"));
+    let b64 = "AAAAAwAAAAA2Pqo4Z4QfutD07YjHeeT+ZuVqJHDcmMDsnAc9BcexAw==";
+    let balance = ClaimableBalance::V0(key());
+    assert_eq!(balance.to_xdr().as_slice(), xdr);
+    assert_eq!(balance.to_xdr_base64().as_str(), b64);
+    assert_eq!(ClaimableBalance::from_xdr(&xdr), Ok(balance));
+    assert_eq!(ClaimableBalance::from_xdr_base64(b64), Ok(balance));
+
+    let xdr = unhex(&format!("00000004{KEY}"));
+    let b64 = "AAAABDY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED";
+    let pool = LiquidityPool(key());
+    assert_eq!(pool.to_xdr().as_slice(), xdr);
+    assert_eq!(pool.to_xdr_base64().as_str(), b64);
+    assert_eq!(LiquidityPool::from_xdr(&xdr), Ok(pool));
+    assert_eq!(LiquidityPool::from_xdr_base64(b64), Ok(pool));
+}
+
+#[test]
+fn test_signer_key() {
+    let xdr = unhex(&format!("00000001{KEY}"));
+    let b64 = "AAAAATY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED";
+    let tx = PreAuthTx(key());
+    assert_eq!(tx.to_xdr().as_slice(), xdr);
+    assert_eq!(tx.to_xdr_base64().as_str(), b64);
+    assert_eq!(PreAuthTx::from_xdr(&xdr), Ok(tx));
+    assert_eq!(PreAuthTx::from_xdr_base64(b64), Ok(tx));
+
+    let xdr = unhex(&format!("00000002{KEY}"));
+    let b64 = "AAAAAjY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7ED";
+    let hash_x = HashX(key());
+    assert_eq!(hash_x.to_xdr().as_slice(), xdr);
+    assert_eq!(hash_x.to_xdr_base64().as_str(), b64);
+    assert_eq!(HashX::from_xdr(&xdr), Ok(hash_x));
+    assert_eq!(HashX::from_xdr_base64(b64), Ok(hash_x));
+}
+
+#[test]
+fn test_signed_payload() {
+    let cases = [
+        (
+            vec![1, 2, 3, 4, 5],
+            format!("00000003{KEY}000000050102030405000000"),
+            "AAAAAzY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7EDAAAABQECAwQFAAAA",
+        ),
+        (
+            vec![0xff; 64],
+            format!("00000003{KEY}00000040{}", "ff".repeat(64)),
+            "AAAAAzY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7EDAAAAQP////////////////////////////////////////////////////////////////////////////////////8=",
+        ),
+    ];
+    for (payload, xdr, b64) in cases {
+        let xdr = unhex(&xdr);
+        let signer = ed25519::SignedPayload::new(ed25519::PublicKey(key()), &payload).unwrap();
+        assert_eq!(signer.to_xdr().as_slice(), xdr);
+        assert_eq!(signer.to_xdr_base64().as_str(), b64);
+        assert_eq!(ed25519::SignedPayload::from_xdr(&xdr), Ok(signer.clone()));
+        assert_eq!(ed25519::SignedPayload::from_xdr_base64(b64), Ok(signer));
+    }
+}
+
+#[test]
+fn test_invalid() {
+    let contract = unhex(&format!("00000001{KEY}"));
+    // Wrong discriminant.
+    assert_eq!(
+        LiquidityPool::from_xdr(&contract),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(HashX::from_xdr(&contract), Err(DecodeError::Invalid));
+    // Truncated and trailing bytes.
+    assert_eq!(
+        Contract::from_xdr(&contract[..35]),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(
+        Contract::from_xdr(&[contract.as_slice(), &[0]].concat()),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(Contract::from_xdr(&[]), Err(DecodeError::Invalid));
+    // Claimable balance ID types other than V0.
+    assert_eq!(
+        ClaimableBalance::from_xdr(&unhex(&format!("0000000300000001{KEY}"))),
+        Err(DecodeError::Invalid)
+    );
+    // Non-zero padding and empty payloads.
+    assert_eq!(
+        ed25519::SignedPayload::from_xdr(&unhex(&format!("00000003{KEY}000000050102030405000001"))),
+        Err(DecodeError::Invalid)
+    );
+    assert_eq!(
+        ed25519::SignedPayload::from_xdr(&unhex(&format!("00000003{KEY}00000000"))),
+        Err(DecodeError::Invalid)
+    );
+    // Base64 that is malformed, unpadded, or too long.
+    for s in [
+        "AAAAATY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7E",
+        "AAAAATY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7E!",
+        "AAAAATY+qjhnhB+60PTtiMd55P5m5WokcNyYwOycBz0Fx7EDAAAA",
+        "",
+    ] {
+        assert_eq!(
+            Contract::from_xdr_base64(s),
+            Err(DecodeError::Invalid),
+            "{s}"
+        );
+    }
+}
+
+#[test]
+fn test_roundtrip_strkey() {
+    // The same 32 bytes as the XDR vectors, in the strkeys used elsewhere.
+    let contract: Contract = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE"
+        .parse()
+        .unwrap();
+    assert_eq!(Contract::from_xdr(&contract.to_xdr()), Ok(contract));
+    let balance: ClaimableBalance = "BAADMPVKHBTYIH522D2O3CGHPHSP4ZXFNISHBXEYYDWJYBZ5AXD3CA3GDE"
+        .parse()
+        .unwrap();
+    assert_eq!(ClaimableBalance::from_xdr(&balance.to_xdr()), Ok(balance));
+}